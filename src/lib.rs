@@ -17,11 +17,21 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
 use core::{
     cmp::Ordering,
     convert::TryFrom,
     fmt,
     hash::{Hash, Hasher},
+    iter::{Product, Sum},
     num::{FpCategory, ParseFloatError},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
     str::FromStr,
@@ -83,10 +93,143 @@ macro_rules! impl_binary_op_alternatives {
     };
 }
 
+macro_rules! impl_primitive_op {
+    (
+        $op:ident for $t:ident,
+        $base:ident,
+        $f:ident,
+        $op_assign:ident,
+        $f_assign:ident,
+        $underflow_sign:expr
+    ) => {
+        /// Computed on the primitive value of `rhs`, so infinite and subnormal operands are used
+        /// as is. The result is rounded as in the operator on two finite floats.
+        ///
+        /// Panics if `rhs` is NaN.
+        impl $op<$base> for $t {
+            type Output = $t;
+
+            #[inline]
+            fn $f(self, rhs: $base) -> $t {
+                let rhs = $t::primitive_operand(rhs);
+                $t::from_primitive_op(self.get().$f(rhs), || {
+                    ($underflow_sign)(self.sign(), $t::primitive_sign(rhs))
+                })
+            }
+        }
+
+        /// Computed on the primitive value of `self`, so infinite and subnormal operands are
+        /// used as is. The result is rounded as in the operator on two finite floats.
+        ///
+        /// Panics if `self` is NaN.
+        impl $op<$t> for $base {
+            type Output = $t;
+
+            #[inline]
+            fn $f(self, rhs: $t) -> $t {
+                let lhs = $t::primitive_operand(self);
+                $t::from_primitive_op(lhs.$f(rhs.get()), || {
+                    ($underflow_sign)($t::primitive_sign(lhs), rhs.sign())
+                })
+            }
+        }
+
+        /// Panics if `rhs` is NaN.
+        impl $op_assign<$base> for $t {
+            #[inline]
+            fn $f_assign(&mut self, rhs: $base) {
+                *self = (*self).$f(rhs);
+            }
+        }
+    };
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Common interface of [`Float32`] and [`Float64`].
+///
+/// This trait is sealed: it can't be implemented outside this crate.
+pub trait FiniteFloat:
+    private::Sealed
+    + Copy
+    + fmt::Debug
+    + fmt::Display
+    + Default
+    + Ord
+    + Hash
+    + FromStr<Err = ParseFiniteFloatError>
+    + Neg<Output = Self>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+    + Sum
+    + Product
+{
+    /// The primitive floating point type.
+    type Primitive: Copy + PartialOrd + From<Self>;
+
+    /// Number of significant digits in base 2.
+    const MANTISSA_DIGITS: u32;
+
+    /// Zero.
+    const ZERO: Self;
+
+    /// One.
+    const ONE: Self;
+
+    /// Difference between 1.0 and the next larger representable number.
+    const EPSILON: Self;
+
+    /// Smallest (negative) value.
+    const MIN: Self;
+
+    /// Largest value.
+    const MAX: Self;
+
+    /// Smallest positive value.
+    const MIN_POSITIVE: Self;
+
+    /// Largest negative value.
+    const MAX_NEGATIVE: Self;
+
+    /// Create a new value.
+    ///
+    /// NaN results in None.
+    fn new(val: Self::Primitive) -> Option<Self>;
+
+    /// Return the value as a primitive type.
+    fn get(self) -> Self::Primitive;
+
+    /// Is the value greater than zero?
+    fn is_positive(self) -> bool;
+
+    /// Is the value less than zero?
+    fn is_negative(self) -> bool;
+
+    /// Is the value zero?
+    fn is_zero(self) -> bool;
+
+    /// Absolute value.
+    fn abs(self) -> Self;
+
+    /// Sign of the number: -1.0, 0.0 or 1.0.
+    fn signum(self) -> Self;
+}
+
 macro_rules! impl_finite_float {
-    ($t:ident, $base:ident) => {
+    ($t:ident, $base:ident, $bits:ident) => {
         /// Finite floating point number.
         #[derive(Clone, Copy, PartialEq, PartialOrd)]
+        #[repr(transparent)]
         pub struct $t($base);
 
         impl $t {
@@ -96,6 +239,9 @@ macro_rules! impl_finite_float {
             /// Zero.
             pub const ZERO: Self = Self(0.0);
 
+            /// One.
+            pub const ONE: Self = Self(1.0);
+
             /// Difference between 1.0 and the next larger representable number.
             pub const EPSILON: Self = Self($base::EPSILON);
 
@@ -111,6 +257,63 @@ macro_rules! impl_finite_float {
             /// Largest negative value.
             pub const MAX_NEGATIVE: Self = Self(-$base::MIN_POSITIVE);
 
+            /// Archimedes' constant (π).
+            pub const PI: Self = Self(core::$base::consts::PI);
+
+            /// The full circle constant (τ = 2π).
+            pub const TAU: Self = Self(2.0 * core::$base::consts::PI);
+
+            /// π/2.
+            pub const FRAC_PI_2: Self = Self(core::$base::consts::FRAC_PI_2);
+
+            /// π/3.
+            pub const FRAC_PI_3: Self = Self(core::$base::consts::FRAC_PI_3);
+
+            /// π/4.
+            pub const FRAC_PI_4: Self = Self(core::$base::consts::FRAC_PI_4);
+
+            /// π/6.
+            pub const FRAC_PI_6: Self = Self(core::$base::consts::FRAC_PI_6);
+
+            /// π/8.
+            pub const FRAC_PI_8: Self = Self(core::$base::consts::FRAC_PI_8);
+
+            /// 1/π.
+            pub const FRAC_1_PI: Self = Self(core::$base::consts::FRAC_1_PI);
+
+            /// 2/π.
+            pub const FRAC_2_PI: Self = Self(core::$base::consts::FRAC_2_PI);
+
+            /// 2/sqrt(π).
+            pub const FRAC_2_SQRT_PI: Self = Self(core::$base::consts::FRAC_2_SQRT_PI);
+
+            /// sqrt(2).
+            pub const SQRT_2: Self = Self(core::$base::consts::SQRT_2);
+
+            /// 1/sqrt(2).
+            pub const FRAC_1_SQRT_2: Self = Self(core::$base::consts::FRAC_1_SQRT_2);
+
+            /// Euler's number (e).
+            pub const E: Self = Self(core::$base::consts::E);
+
+            /// log<sub>2</sub>(e).
+            pub const LOG2_E: Self = Self(core::$base::consts::LOG2_E);
+
+            /// log<sub>2</sub>(10).
+            pub const LOG2_10: Self = Self(core::$base::consts::LOG2_10);
+
+            /// log<sub>10</sub>(e).
+            pub const LOG10_E: Self = Self(core::$base::consts::LOG10_E);
+
+            /// log<sub>10</sub>(2).
+            pub const LOG10_2: Self = Self(core::$base::consts::LOG10_2);
+
+            /// ln(2).
+            pub const LN_2: Self = Self(core::$base::consts::LN_2);
+
+            /// ln(10).
+            pub const LN_10: Self = Self(core::$base::consts::LN_10);
+
             /// Create a new value.
             ///
             /// NaN results in None.
@@ -123,12 +326,170 @@ macro_rules! impl_finite_float {
                 }
             }
 
+            /// Create a new value, mapping NaN to `ZERO`.
+            ///
+            /// Infinities saturate to `MAX` or `MIN`, subnormal numbers round away from zero.
+            #[inline]
+            pub fn saturating_from(val: $base) -> Self {
+                Self::new(val).unwrap_or(Self::ZERO)
+            }
+
+            /// View a primitive value in place.
+            ///
+            /// Returns `None` unless `val` is already a valid value: not NaN, infinite,
+            /// subnormal or negative zero.
+            #[inline]
+            pub fn from_primitive_ref(val: &$base) -> Option<&Self> {
+                if Self::is_valid_primitive(*val) {
+                    // SAFETY: `Self` is `repr(transparent)` over `$base` and `*val` satisfies its
+                    // invariant.
+                    Some(unsafe { &*(val as *const $base as *const Self) })
+                } else {
+                    None
+                }
+            }
+
+            /// Modify a primitive value in place.
+            ///
+            /// Returns `None` unless `val` is already a valid value: not NaN, infinite,
+            /// subnormal or negative zero.
+            #[inline]
+            pub fn from_primitive_mut(val: &mut $base) -> Option<&mut Self> {
+                if Self::is_valid_primitive(*val) {
+                    // SAFETY: `Self` is `repr(transparent)` over `$base` and `*val` satisfies its
+                    // invariant. While the borrow lasts, only valid values can be written.
+                    Some(unsafe { &mut *(val as *mut $base as *mut Self) })
+                } else {
+                    None
+                }
+            }
+
+            /// View a slice of primitive values in place.
+            ///
+            /// Returns `None` unless all elements are valid values, as in `from_primitive_ref`.
+            #[inline]
+            pub fn from_slice(vals: &[$base]) -> Option<&[Self]> {
+                if vals.iter().all(|&val| Self::is_valid_primitive(val)) {
+                    // SAFETY: `Self` is `repr(transparent)` over `$base` and all elements satisfy
+                    // its invariant.
+                    Some(unsafe {
+                        core::slice::from_raw_parts(vals.as_ptr() as *const Self, vals.len())
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Modify a slice of primitive values in place.
+            ///
+            /// Returns `None` unless all elements are valid values, as in `from_primitive_mut`.
+            #[inline]
+            pub fn from_slice_mut(vals: &mut [$base]) -> Option<&mut [Self]> {
+                if vals.iter().all(|&val| Self::is_valid_primitive(val)) {
+                    // SAFETY: `Self` is `repr(transparent)` over `$base` and all elements satisfy
+                    // its invariant. While the borrow lasts, only valid values can be written.
+                    Some(unsafe {
+                        core::slice::from_raw_parts_mut(vals.as_mut_ptr() as *mut Self, vals.len())
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Convert a slice of primitive values as in `saturating_from`.
+            #[cfg(feature = "alloc")]
+            pub fn from_slice_sanitized(vals: &[$base]) -> Vec<Self> {
+                vals.iter().map(|&val| Self::saturating_from(val)).collect()
+            }
+
+            /// Shortest decimal representation that parses back to the same value.
+            #[cfg(feature = "alloc")]
+            pub fn to_exact_string(self) -> String {
+                self.get().to_string()
+            }
+
+            /// Is `val` representable without conversion?
+            #[inline]
+            fn is_valid_primitive(val: $base) -> bool {
+                val.is_normal() || val.to_bits() == 0
+            }
+
             /// Return the value as a primitive type.
             #[inline]
-            pub fn get(self) -> $base {
+            pub const fn get(self) -> $base {
                 self.0
             }
 
+            /// Raw transmutation to the bits of the primitive type.
+            #[inline]
+            pub fn to_bits(self) -> $bits {
+                self.get().to_bits()
+            }
+
+            /// Raw transmutation from the bits of the primitive type.
+            ///
+            /// NaN results in `ZERO`.
+            #[inline]
+            pub fn from_bits(bits: $bits) -> Self {
+                Self::saturating_from($base::from_bits(bits))
+            }
+
+            /// Memory representation in little-endian byte order.
+            #[inline]
+            pub fn to_le_bytes(self) -> [u8; core::mem::size_of::<$base>()] {
+                self.get().to_le_bytes()
+            }
+
+            /// Memory representation in big-endian byte order.
+            #[inline]
+            pub fn to_be_bytes(self) -> [u8; core::mem::size_of::<$base>()] {
+                self.get().to_be_bytes()
+            }
+
+            /// Create from a little-endian memory representation.
+            ///
+            /// Converted as in `from_bits`: NaN results in `ZERO`, infinities saturate.
+            #[inline]
+            pub fn from_le_bytes(bytes: [u8; core::mem::size_of::<$base>()]) -> Self {
+                Self::from_bits($bits::from_le_bytes(bytes))
+            }
+
+            /// Create from a big-endian memory representation.
+            ///
+            /// Converted as in `from_bits`: NaN results in `ZERO`, infinities saturate.
+            #[inline]
+            pub fn from_be_bytes(bytes: [u8; core::mem::size_of::<$base>()]) -> Self {
+                Self::from_bits($bits::from_be_bytes(bytes))
+            }
+
+            /// Bits whose unsigned ordering matches the ordering of values.
+            ///
+            /// Useful as a key for radix sorting or for ordered byte-wise storage.
+            #[inline]
+            pub fn to_ordered_bits(self) -> $bits {
+                let bits = self.to_bits();
+                let sign_bit = !($bits::MAX >> 1);
+                if bits & sign_bit != 0 {
+                    !bits
+                } else {
+                    bits | sign_bit
+                }
+            }
+
+            /// Inverse of `to_ordered_bits`.
+            ///
+            /// Bits that don't correspond to a value are converted as in `from_bits`: NaN results
+            /// in `ZERO`, subnormal numbers round away from zero.
+            #[inline]
+            pub fn from_ordered_bits(bits: $bits) -> Self {
+                let sign_bit = !($bits::MAX >> 1);
+                if bits & sign_bit != 0 {
+                    Self::from_bits(bits ^ sign_bit)
+                } else {
+                    Self::from_bits(!bits)
+                }
+            }
+
             /// `val` can't be NaN
             ///
             /// `underflow_sign` is called when `val` is 0.0, in which case it indicates
@@ -163,172 +524,1295 @@ macro_rules! impl_finite_float {
                 }
             }
 
+            /// Operand of a mixed-primitive operator. -0.0 is replaced by 0.0.
             #[inline]
-            fn from_primitive(val: $base) -> Self {
-                Self::from_primitive_with_underflow_sign(val, || Ordering::Equal)
+            fn primitive_operand(val: $base) -> $base {
+                if val.is_nan() {
+                    panic!("NaN operand of {}", stringify!($t));
+                }
+                if val == 0.0 {
+                    0.0
+                } else {
+                    val
+                }
             }
 
+            /// Sign of a primitive operand.
             #[inline]
-            fn sign(self) -> Ordering {
-                self.cmp(&Self::ZERO)
+            fn primitive_sign(val: $base) -> Ordering {
+                if val > 0.0 {
+                    Ordering::Greater
+                } else if val < 0.0 {
+                    Ordering::Less
+                } else {
+                    Ordering::Equal
+                }
             }
 
-
-            /// Euclidean division.
-            #[cfg(feature = "std")]
+            /// Result of a mixed-primitive operator.
+            ///
+            /// NaN comes from `0 * inf` or `0 / 0` and results in `ZERO`.
             #[inline]
-            pub fn div_euclid(self, rhs: Self) -> Self {
-                let res = self.get().div_euclid(rhs.get());
+            fn from_primitive_op<US>(res: $base, underflow_sign: US) -> Self
+            where
+                US: FnOnce() -> Ordering,
+            {
                 if res.is_nan() {
-                    // (0.0).div_euclid(0.0) = MAX
-                    Self::MAX
+                    Self::ZERO
                 } else {
-                    // Result is an integer: if underflow, it's 0.
-                    Self::from_primitive(res)
+                    Self::from_primitive_with_underflow_sign(res, underflow_sign)
                 }
             }
 
-           /// Euclidean remainder.
-           #[cfg(feature = "std")]
-           #[inline]
-           pub fn rem_euclid(self, rhs: Self) -> Self {
-               let res = self.get().rem_euclid(rhs.get());
-               if res.is_nan() {
-                   // self.rem_euclid(0.0) = 0.0
-                   Self::ZERO
-               } else {
-                   // res == 0.0 iff exact multiple
-                   Self::from_primitive(res)
-               }
-           }
+            #[inline]
+            fn from_primitive(val: $base) -> Self {
+                Self::from_primitive_with_underflow_sign(val, || Ordering::Equal)
+            }
 
-        }
+            /// `val * 2^exponent`, rounding only if the result is subnormal.
+            fn mul_pow2(mut val: $base, mut exponent: i32) -> $base {
+                // 2^STEP is exactly representable.
+                const STEP: i32 = 60;
+                if val == 0.0 {
+                    return val;
+                }
+                let step = (1u64 << STEP) as $base;
+                while exponent > STEP && !val.is_infinite() {
+                    val *= step;
+                    exponent -= STEP;
+                }
+                while exponent < -STEP && val != 0.0 {
+                    val /= step;
+                    exponent += STEP;
+                }
+                if !(-STEP..=STEP).contains(&exponent) {
+                    // Overflowed or underflowed.
+                    val
+                } else if exponent >= 0 {
+                    val * (1u64 << exponent) as $base
+                } else {
+                    val / (1u64 << -exponent) as $base
+                }
+            }
 
-        impl Eq for $t {}
+            #[inline]
+            fn sign(self) -> Ordering {
+                self.cmp(&Self::ZERO)
+            }
 
-        #[allow(clippy::derive_ord_xor_partial_ord)]
-        impl Ord for $t {
+            /// Is the value greater than zero?
             #[inline]
-            fn cmp(&self, other: &Self) -> Ordering {
-                self.partial_cmp(other).unwrap()
+            pub fn is_positive(self) -> bool {
+                self.sign() == Ordering::Greater
             }
-        }
 
-        impl Default for $t {
+            /// Is the value less than zero?
             #[inline]
-            fn default() -> Self {
-                Self::ZERO
+            pub fn is_negative(self) -> bool {
+                self.sign() == Ordering::Less
             }
-        }
 
-        #[allow(clippy::derive_hash_xor_eq)]
-        impl Hash for $t {
+            /// Is the value zero?
             #[inline]
-            fn hash<H>(&self, state: &mut H)
-            where H: Hasher
-            {
-                self.get().to_bits().hash(state)
+            pub fn is_zero(self) -> bool {
+                self.sign() == Ordering::Equal
             }
-        }
 
-        impl From<$t> for $base {
+            /// Always `true`, for compatibility with primitive floats.
             #[inline]
-            fn from(val: $t) -> Self {
-                val.get()
+            pub fn is_finite(self) -> bool {
+                true
             }
-        }
 
-        impl TryFrom<$base> for $t {
-            type Error = NanError;
+            /// Always `false`, for compatibility with primitive floats.
+            #[inline]
+            pub fn is_infinite(self) -> bool {
+                false
+            }
 
+            /// Always `false`, for compatibility with primitive floats.
             #[inline]
-            fn try_from(val: $base) -> Result<$t, NanError> {
-                $t::new(val).ok_or(NanError)
+            pub fn is_nan(self) -> bool {
+                false
             }
-        }
 
-        impl_fmt!(Debug for $t);
-        impl_fmt!(Display for $t);
-        impl_fmt!(LowerExp for $t);
-        impl_fmt!(UpperExp for $t);
+            /// Is the value normal, i.e. not zero?
+            ///
+            /// There are no subnormal values, so this is `true` for all non-zero values.
+            #[inline]
+            pub fn is_normal(self) -> bool {
+                !self.is_zero()
+            }
 
-        impl FromStr for $t {
-            type Err = ParseFloatError;
+            /// Replace `self` with `-self`.
+            ///
+            /// `ZERO` stays positive.
+            #[inline]
+            pub fn negate_in_place(&mut self) {
+                *self = -*self;
+            }
 
-            fn from_str(s: &str) -> Result<Self, ParseFloatError> {
-                let val = $base::from_str(s)?;
-                if val.is_nan() {
-                    Err($base::from_str("NaN value is invalid").unwrap_err())
+            /// Category of the number.
+            #[inline]
+            pub fn classify(self) -> FiniteCategory {
+                if self.is_zero() {
+                    FiniteCategory::Zero
                 } else {
-                    Ok(Self::from_primitive_with_underflow_sign(val, || parse_sign_of_tiny_float(s)))
+                    FiniteCategory::Normal
                 }
             }
-        }
-
-        impl Neg for $t {
-            type Output = Self;
 
+            /// Absolute value.
             #[inline]
-            fn neg(self) -> Self {
-                Self::from_primitive(-self.get())
+            pub fn abs(self) -> Self {
+                if self < Self::ZERO {
+                    -self
+                } else {
+                    self
+                }
             }
-        }
-
-        impl Neg for &$t {
-            type Output = $t;
 
+            /// Absolute difference, `(self - other).abs()`.
+            ///
+            /// Saturates to `MAX` if the difference overflows.
             #[inline]
-            fn neg(self) -> $t {
-                (*self).neg()
+            pub fn abs_diff(self, other: Self) -> Self {
+                (self - other).abs()
             }
-        }
-
-        impl Add for $t {
-            type Output = Self;
 
+            /// Magnitude of `self` with the sign of `sign`.
+            ///
+            /// The sign of `ZERO` is positive.
             #[inline]
-            fn add(self, rhs: Self) -> Self {
-                // Result is 0 iff self == -rhs.
-                Self::from_primitive(self.get() + rhs.get())
+            pub fn copysign(self, sign: Self) -> Self {
+                if sign < Self::ZERO {
+                    -self.abs()
+                } else {
+                    self.abs()
+                }
             }
-        }
-
-        impl_binary_op_alternatives!(Add for $t, add, AddAssign, add_assign);
-
-        impl Sub for $t {
-            type Output = Self;
 
+            /// Sign of the number: -1.0, 0.0 or 1.0.
+            ///
+            /// Unlike the primitive `signum`, zero maps to zero.
             #[inline]
-            fn sub(self, rhs: Self) -> Self {
-                // Result is 0 iff self == rhs.
-                Self::from_primitive(self.get() - rhs.get())
+            pub fn signum(self) -> Self {
+                match self.sign() {
+                    Ordering::Less => Self(-1.0),
+                    Ordering::Equal => Self::ZERO,
+                    Ordering::Greater => Self(1.0),
+                }
             }
-        }
 
-        impl_binary_op_alternatives!(Sub for $t, sub, SubAssign, sub_assign);
+            /// Sign of the number: -1.0 or 1.0, or `zero_sign` if `self` is zero.
+            #[inline]
+            pub fn signum_or(self, zero_sign: Self) -> Self {
+                if self.is_zero() {
+                    zero_sign
+                } else {
+                    self.signum()
+                }
+            }
 
-        impl Mul for $t {
-            type Output = Self;
+            /// Minimum of two numbers.
+            #[inline]
+            pub fn min(self, other: Self) -> Self {
+                Ord::min(self, other)
+            }
 
+            /// Maximum of two numbers.
             #[inline]
-            fn mul(self, rhs: Self) -> Self {
-                Self::from_primitive_with_underflow_sign(
-                    self.get() * rhs.get(),
-                    || multiply_signs(self.sign(), rhs.sign()))
+            pub fn max(self, other: Self) -> Self {
+                Ord::max(self, other)
             }
-        }
 
-        impl_binary_op_alternatives!(Mul for $t, mul, MulAssign, mul_assign);
+            /// Compare absolute values.
+            #[inline]
+            pub fn cmp_magnitude(self, other: Self) -> Ordering {
+                self.abs().cmp(&other.abs())
+            }
 
-        impl Div for $t {
-            type Output = Self;
+            /// The number with the smaller absolute value.
+            ///
+            /// If the absolute values are equal, returns the smaller number.
+            #[inline]
+            pub fn min_by_magnitude(self, other: Self) -> Self {
+                match self.cmp_magnitude(other) {
+                    Ordering::Less => self,
+                    Ordering::Equal => self.min(other),
+                    Ordering::Greater => other,
+                }
+            }
 
+            /// The number with the larger absolute value.
+            ///
+            /// If the absolute values are equal, returns the larger number.
+            #[inline]
+            pub fn max_by_magnitude(self, other: Self) -> Self {
+                match self.cmp_magnitude(other) {
+                    Ordering::Less => other,
+                    Ordering::Equal => self.max(other),
+                    Ordering::Greater => self,
+                }
+            }
+
+            /// Restrict the value to the range `[min, max]`.
+            ///
+            /// Panics in debug mode if `min > max`.
+            #[inline]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                debug_assert!(min <= max);
+                if self < min {
+                    min
+                } else if self > max {
+                    max
+                } else {
+                    self
+                }
+            }
+
+            /// Restrict the value to the unit interval `[ZERO, ONE]`.
+            #[inline]
+            pub fn clamp01(self) -> Self {
+                self.clamp(Self::ZERO, Self::ONE)
+            }
+
+            /// Restrict the magnitude to at most `max_abs`, keeping the sign.
+            ///
+            /// Panics if `max_abs` is negative.
+            #[inline]
+            pub fn clamp_magnitude(self, max_abs: Self) -> Self {
+                assert!(max_abs >= Self::ZERO, "negative max_abs");
+                if self.abs() <= max_abs {
+                    self
+                } else {
+                    max_abs.copysign(self)
+                }
+            }
+
+            /// Smallest value greater than `self`.
+            ///
+            /// `MAX.next_up()` is `MAX`. `ZERO.next_up()` is `MIN_POSITIVE`.
+            #[inline]
+            pub fn next_up(self) -> Self {
+                if self == Self::MAX {
+                    Self::MAX
+                } else if self == Self::ZERO {
+                    Self::MIN_POSITIVE
+                } else if self == Self::MAX_NEGATIVE {
+                    Self::ZERO
+                } else if self > Self::ZERO {
+                    Self($base::from_bits(self.get().to_bits() + 1))
+                } else {
+                    Self($base::from_bits(self.get().to_bits() - 1))
+                }
+            }
+
+            /// Largest value less than `self`.
+            ///
+            /// `MIN.next_down()` is `MIN`. `ZERO.next_down()` is `MAX_NEGATIVE`.
+            #[inline]
+            pub fn next_down(self) -> Self {
+                -(-self).next_up()
+            }
+
+            /// Adjacent value in the direction of `toward`.
+            ///
+            /// Returns `self` if `self == toward`. Like `next_up` and `next_down`, steps from
+            /// `ZERO` to `MIN_POSITIVE` or `MAX_NEGATIVE`.
+            #[inline]
+            pub fn nextafter(self, toward: Self) -> Self {
+                match self.cmp(&toward) {
+                    Ordering::Less => self.next_up(),
+                    Ordering::Equal => self,
+                    Ordering::Greater => self.next_down(),
+                }
+            }
+
+            /// Spacing between representable values at the magnitude of `self`.
+            ///
+            /// This is the distance from `self.abs()` to the next larger value, or to the next
+            /// smaller value at `MAX`. Spacings smaller than `MIN_POSITIVE` are rounded up to
+            /// `MIN_POSITIVE`.
+            #[inline]
+            pub fn ulp(self) -> Self {
+                let a = self.abs();
+                if a == Self::MAX {
+                    a - a.next_down()
+                } else {
+                    a.next_up() - a
+                }
+            }
+
+            /// `self * 2^n`, also known as `ldexp`.
+            ///
+            /// Overflow saturates to `MAX` or `MIN`. Underflow results in `MIN_POSITIVE` or
+            /// `MAX_NEGATIVE`.
+            #[inline]
+            pub fn scalb(self, n: i32) -> Self {
+                Self::from_primitive_with_underflow_sign(Self::mul_pow2(self.get(), n), || {
+                    self.sign()
+                })
+            }
+
+            /// Splits `self` into a mantissa and an exponent such that
+            /// `self == mantissa.scalb(exponent)`.
+            ///
+            /// The magnitude of the mantissa is in `[0.5, 1)`. `ZERO` results in `(ZERO, 0)`.
+            #[inline]
+            pub fn frexp(self) -> (Self, i32) {
+                if self == Self::ZERO {
+                    return (Self::ZERO, 0);
+                }
+                // All non-zero values are normal.
+                let shift = Self::MANTISSA_DIGITS - 1;
+                let exponent_mask: $bits = ($bits::MAX >> 1) >> shift;
+                let bits = self.to_bits();
+                let biased_exponent = ((bits >> shift) & exponent_mask) as i32;
+                // Biased exponent of the mantissa.
+                let half = ($base::MAX_EXP - 2) as $bits;
+                let mantissa = (bits & !(exponent_mask << shift)) | (half << shift);
+                (
+                    Self($base::from_bits(mantissa)),
+                    biased_exponent - ($base::MAX_EXP - 2),
+                )
+            }
+
+            /// Iterate from `self` to `end`, inclusive, in increments of `step`.
+            ///
+            /// `step` may be negative, in which case `end` should be at most `self`.
+            /// Iteration stops before passing `end`, or when adding `step` no longer changes the
+            /// value.
+            #[inline]
+            pub fn iter_to(self, end: Self, step: Self) -> impl Iterator<Item = Self> {
+                let in_range = move |x: Self| {
+                    if step.is_negative() {
+                        x >= end
+                    } else {
+                        x <= end
+                    }
+                };
+                let first = if in_range(self) { Some(self) } else { None };
+                core::iter::successors(first, move |&x| {
+                    let next = x + step;
+                    if next != x && in_range(next) {
+                        Some(next)
+                    } else {
+                        None
+                    }
+                })
+            }
+
+            /// Addition that returns `None` on overflow.
+            #[inline]
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.overflowing_add(rhs) {
+                    (res, false) => Some(res),
+                    (_, true) => None,
+                }
+            }
+
+            /// Subtraction that returns `None` on overflow.
+            #[inline]
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                match self.overflowing_sub(rhs) {
+                    (res, false) => Some(res),
+                    (_, true) => None,
+                }
+            }
+
+            /// Multiplication that returns `None` on overflow.
+            ///
+            /// Underflow is not an error.
+            #[inline]
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                match self.overflowing_mul(rhs) {
+                    (res, false) => Some(res),
+                    (_, true) => None,
+                }
+            }
+
+            /// Negation that returns `None` on overflow.
+            ///
+            /// Negation never overflows, so this always returns `Some`. Provided for symmetry
+            /// with the other `checked_*` methods.
+            #[inline]
+            pub fn checked_neg(self) -> Option<Self> {
+                Some(-self)
+            }
+
+            /// Addition that returns an error on overflow.
+            #[inline]
+            pub fn try_add(self, rhs: Self) -> Result<Self, OverflowError> {
+                self.checked_add(rhs).ok_or(OverflowError)
+            }
+
+            /// Subtraction that returns an error on overflow.
+            #[inline]
+            pub fn try_sub(self, rhs: Self) -> Result<Self, OverflowError> {
+                self.checked_sub(rhs).ok_or(OverflowError)
+            }
+
+            /// Multiplication that returns an error on overflow.
+            ///
+            /// Underflow is not an error.
+            #[inline]
+            pub fn try_mul(self, rhs: Self) -> Result<Self, OverflowError> {
+                self.checked_mul(rhs).ok_or(OverflowError)
+            }
+
+            /// Division that returns an error on overflow.
+            ///
+            /// Division by zero is an error, including `ZERO / ZERO`. Underflow is not an error.
+            #[inline]
+            pub fn try_div(self, rhs: Self) -> Result<Self, OverflowError> {
+                let res = self.get() / rhs.get();
+                if res.is_finite() {
+                    Ok(Self::from_primitive_with_underflow_sign(res, || {
+                        multiply_signs(self.sign(), rhs.sign())
+                    }))
+                } else {
+                    Err(OverflowError)
+                }
+            }
+
+            /// Saturating addition, along with a flag indicating whether it overflowed.
+            #[inline]
+            pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let res = self.get() + rhs.get();
+                (Self::from_primitive(res), res.is_infinite())
+            }
+
+            /// Saturating subtraction, along with a flag indicating whether it overflowed.
+            #[inline]
+            pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let res = self.get() - rhs.get();
+                (Self::from_primitive(res), res.is_infinite())
+            }
+
+            /// Saturating multiplication, along with a flag indicating whether it overflowed.
+            ///
+            /// Underflow is not reported.
+            #[inline]
+            pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                let res = self.get() * rhs.get();
+                (
+                    Self::from_primitive_with_underflow_sign(
+                        res,
+                        || multiply_signs(self.sign(), rhs.sign())),
+                    res.is_infinite(),
+                )
+            }
+
+            /// Linear interpolation between `a` (at `t = 0`) and `b` (at `t = 1`).
+            ///
+            /// The result is exact at both endpoints.
+            #[inline]
+            pub fn lerp(a: Self, b: Self, t: Self) -> Self {
+                if t == Self(1.0) {
+                    // a + (b - a) may not be exactly b.
+                    return b;
+                }
+                match b.overflowing_sub(a) {
+                    (diff, false) => a + diff * t,
+                    // Interpolate without computing b - a.
+                    (_, true) => a * (Self(1.0) - t) + b * t,
+                }
+            }
+
+            /// Inverse of `lerp`: the `t` such that `lerp(a, b, t)` is `self`.
+            ///
+            /// If `a == b`, the result is `ZERO`.
+            #[inline]
+            pub fn unlerp(self, a: Self, b: Self) -> Self {
+                if a == b {
+                    return Self::ZERO;
+                }
+                match b.overflowing_sub(a) {
+                    (diff, false) => (self - a) / diff,
+                    (_, true) => {
+                        // Halve everything so that the differences don't overflow.
+                        let num = self.get() / 2.0 - a.get() / 2.0;
+                        let den = b.get() / 2.0 - a.get() / 2.0;
+                        Self::from_primitive_with_underflow_sign(num / den, || {
+                            multiply_signs(self.cmp(&a), b.cmp(&a))
+                        })
+                    }
+                }
+            }
+
+            /// Map `self` linearly from the range `[from_lo, from_hi]` to `[to_lo, to_hi]`.
+            ///
+            /// If `from_lo == from_hi`, the result is `to_lo`.
+            #[inline]
+            pub fn remap(self, from_lo: Self, from_hi: Self, to_lo: Self, to_hi: Self) -> Self {
+                Self::lerp(to_lo, to_hi, self.unlerp(from_lo, from_hi))
+            }
+
+            /// Average of `self` and `other`, computed without intermediate overflow.
+            #[inline]
+            pub fn midpoint(self, other: Self) -> Self {
+                const LO: $base = $base::MIN_POSITIVE * 2.0;
+                const HI: $base = $base::MAX / 2.0;
+                let a = self.get();
+                let b = other.get();
+                let abs_a = self.abs().get();
+                let abs_b = other.abs().get();
+                let res = if abs_a <= HI && abs_b <= HI {
+                    (a + b) / 2.0
+                } else if abs_a < LO {
+                    a + b / 2.0
+                } else if abs_b < LO {
+                    a / 2.0 + b
+                } else {
+                    a / 2.0 + b / 2.0
+                };
+                // The sign of the true result is the sign of self + other.
+                Self::from_primitive_with_underflow_sign(res, || self.cmp(&-other))
+            }
+
+            /// `self * self`.
+            #[inline]
+            pub fn square(self) -> Self {
+                self * self
+            }
+
+            /// `self * self * self`.
+            #[inline]
+            pub fn cube(self) -> Self {
+                self * self * self
+            }
+
+            /// Convert a fraction to a percentage, `self * 100`.
+            #[inline]
+            pub fn to_percent(self) -> Self {
+                self * Self(100.0)
+            }
+
+            /// Convert a percentage to a fraction, `p / 100`.
+            #[inline]
+            pub fn from_percent(p: Self) -> Self {
+                p / Self(100.0)
+            }
+
+            /// Reciprocal, `1 / self`.
+            ///
+            /// `ZERO.recip()` is `MAX`.
+            #[inline]
+            pub fn recip(self) -> Self {
+                Self::from_primitive_with_underflow_sign(1.0 / self.get(), || self.sign())
+            }
+
+            /// Fused multiply-add, `self * a + b` with a single rounding.
+            ///
+            /// If the result underflows to zero, the sign of the true result is not
+            /// tracked and the result is `ZERO`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn mul_add(self, a: Self, b: Self) -> Self {
+                Self::from_primitive(self.get().mul_add(a.get(), b.get()))
+            }
+
+            /// Raise to an integer power.
+            ///
+            /// `ZERO` raised to a negative power is `MAX`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn powi(self, n: i32) -> Self {
+                Self::from_primitive_with_underflow_sign(self.get().powi(n), || {
+                    if n % 2 == 0 {
+                        multiply_signs(self.sign(), self.sign())
+                    } else {
+                        self.sign()
+                    }
+                })
+            }
+
+            /// Raise to a floating point power.
+            ///
+            /// A negative number raised to a non-integer power is `ZERO`.
+            /// `ZERO` raised to a negative power is `MAX`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn powf(self, exp: Self) -> Self {
+                let res = self.get().powf(exp.get());
+                if res.is_nan() {
+                    // Negative base, non-integer exponent.
+                    Self::ZERO
+                } else {
+                    Self::from_primitive_with_underflow_sign(res, || {
+                        if self < Self::ZERO && exp.get() % 2.0 != 0.0 {
+                            // Odd exponent.
+                            Ordering::Less
+                        } else {
+                            multiply_signs(self.sign(), self.sign())
+                        }
+                    })
+                }
+            }
+
+            /// Raise to a floating point power, or `None` if the result is undefined.
+            ///
+            /// The result is undefined for a negative number raised to a non-integer power.
+            /// Overflow saturates as in `powf`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn checked_powf(self, exp: Self) -> Option<Self> {
+                if self.get().powf(exp.get()).is_nan() {
+                    None
+                } else {
+                    Some(self.powf(exp))
+                }
+            }
+
+            /// Square root.
+            ///
+            /// The square root of a negative number is `ZERO`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn sqrt(self) -> Self {
+                if self < Self::ZERO {
+                    Self::ZERO
+                } else {
+                    Self::from_primitive(self.get().sqrt())
+                }
+            }
+
+            /// Square root, or `None` if `self` is negative.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn checked_sqrt(self) -> Option<Self> {
+                if self < Self::ZERO {
+                    None
+                } else {
+                    Some(self.sqrt())
+                }
+            }
+
+            /// Cube root.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn cbrt(self) -> Self {
+                Self::from_primitive(self.get().cbrt())
+            }
+
+            /// Length of the hypotenuse of a right-angle triangle with legs `self` and `other`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn hypot(self, other: Self) -> Self {
+                Self::from_primitive(self.get().hypot(other.get()))
+            }
+
+            /// Largest integer less than or equal to `self`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn floor(self) -> Self {
+                Self::from_primitive(self.get().floor())
+            }
+
+            /// Smallest integer greater than or equal to `self`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn ceil(self) -> Self {
+                Self::from_primitive(self.get().ceil())
+            }
+
+            /// Nearest integer, with ties rounded away from zero.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn round(self) -> Self {
+                Self::from_primitive(self.get().round())
+            }
+
+            /// Nearest integer, with ties rounded to even.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn round_ties_even(self) -> Self {
+                let val = self.get();
+                let rounded = val.round();
+                if (rounded - val).abs() == 0.5 {
+                    // Ties are small enough that halving is exact.
+                    Self::from_primitive(2.0 * (val / 2.0).round())
+                } else {
+                    Self::from_primitive(rounded)
+                }
+            }
+
+            /// Integer part of `self`, rounding towards zero.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn trunc(self) -> Self {
+                Self::from_primitive(self.get().trunc())
+            }
+
+            /// Fractional part of `self`, `self - self.trunc()`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn fract(self) -> Self {
+                Self::from_primitive(self.get().fract())
+            }
+
+            /// Is the value an integer?
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn is_integer(self) -> bool {
+                self.fract() == Self::ZERO
+            }
+
+            /// Convert to `i64` if the value is an integer in range.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn to_integer_exact(self) -> Option<i64> {
+                // i64::MIN is a power of 2, so it converts exactly.
+                let min = i64::MIN as $base;
+                if self.is_integer() && self.get() >= min && self.get() < -min {
+                    Some(self.get() as i64)
+                } else {
+                    None
+                }
+            }
+
+            /// Exponential function, `e^self`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn exp(self) -> Self {
+                Self::from_primitive_with_underflow_sign(self.get().exp(), || Ordering::Greater)
+            }
+
+            /// Natural logarithm.
+            ///
+            /// `ZERO.ln()` is `MIN`. The logarithm of a negative number is `ZERO`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn ln(self) -> Self {
+                if self < Self::ZERO {
+                    Self::ZERO
+                } else {
+                    Self::from_primitive(self.get().ln())
+                }
+            }
+
+            /// Natural logarithm, or `None` if `self` is not positive.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn checked_ln(self) -> Option<Self> {
+                if self.is_positive() {
+                    Some(self.ln())
+                } else {
+                    None
+                }
+            }
+
+            /// Base 2 logarithm.
+            ///
+            /// `ZERO.log2()` is `MIN`. The logarithm of a negative number is `ZERO`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn log2(self) -> Self {
+                if self < Self::ZERO {
+                    Self::ZERO
+                } else {
+                    Self::from_primitive(self.get().log2())
+                }
+            }
+
+            /// Base 10 logarithm.
+            ///
+            /// `ZERO.log10()` is `MIN`. The logarithm of a negative number is `ZERO`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn log10(self) -> Self {
+                if self < Self::ZERO {
+                    Self::ZERO
+                } else {
+                    Self::from_primitive(self.get().log10())
+                }
+            }
+
+            /// Logarithm with respect to an arbitrary base.
+            ///
+            /// `ZERO.log(base)` saturates to `MIN` for `base > 1` and to `MAX` for `base < 1`.
+            /// The logarithm of a negative number is `ZERO`. If `base` is not positive or is
+            /// 1.0, the result is `ZERO`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn log(self, base: Self) -> Self {
+                if self < Self::ZERO || base <= Self::ZERO || base.get() == 1.0 {
+                    Self::ZERO
+                } else {
+                    Self::from_primitive(self.get().log(base.get()))
+                }
+            }
+
+            /// `2^self`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn exp2(self) -> Self {
+                Self::from_primitive_with_underflow_sign(self.get().exp2(), || Ordering::Greater)
+            }
+
+            /// `e^self - 1`, accurate even when `self` is close to zero.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn exp_m1(self) -> Self {
+                Self::from_primitive(self.get().exp_m1())
+            }
+
+            /// `ln(1 + self)`, accurate even when `self` is close to zero.
+            ///
+            /// If `self <= -1`, the result is `MIN`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn ln_1p(self) -> Self {
+                if self.get() <= -1.0 {
+                    Self::MIN
+                } else {
+                    Self::from_primitive(self.get().ln_1p())
+                }
+            }
+
+            /// Sine, in radians.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn sin(self) -> Self {
+                Self::from_primitive(self.get().sin())
+            }
+
+            /// Cosine, in radians.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn cos(self) -> Self {
+                Self::from_primitive(self.get().cos())
+            }
+
+            /// Tangent, in radians.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn tan(self) -> Self {
+                Self::from_primitive(self.get().tan())
+            }
+
+            /// Arcsine, in radians.
+            ///
+            /// The argument is first clamped to `[-1, 1]`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn asin(self) -> Self {
+                let x = self.clamp(Self(-1.0), Self(1.0));
+                Self::from_primitive(x.get().asin())
+            }
+
+            /// Arccosine, in radians.
+            ///
+            /// The argument is first clamped to `[-1, 1]`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn acos(self) -> Self {
+                let x = self.clamp(Self(-1.0), Self(1.0));
+                Self::from_primitive(x.get().acos())
+            }
+
+            /// Arctangent, in radians.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn atan(self) -> Self {
+                Self::from_primitive(self.get().atan())
+            }
+
+            /// Four quadrant arctangent of `self` (y) and `other` (x), in radians.
+            ///
+            /// The result is in `[-pi, pi]`. `ZERO.atan2(x)` is pi for negative `x` and
+            /// `ZERO` otherwise.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn atan2(self, other: Self) -> Self {
+                Self::from_primitive_with_underflow_sign(self.get().atan2(other.get()), || self.sign())
+            }
+
+            /// Inverse hyperbolic sine.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn asinh(self) -> Self {
+                Self::from_primitive(self.get().asinh())
+            }
+
+            /// Inverse hyperbolic cosine.
+            ///
+            /// If `self < 1`, the result is `ZERO`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn acosh(self) -> Self {
+                if self.get() < 1.0 {
+                    Self::ZERO
+                } else {
+                    Self::from_primitive(self.get().acosh())
+                }
+            }
+
+            /// Inverse hyperbolic tangent.
+            ///
+            /// `atanh(1)` is `MAX` and `atanh(-1)` is `MIN`. Outside of `[-1, 1]` the result
+            /// is `ZERO`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn atanh(self) -> Self {
+                if self.get() < -1.0 || self.get() > 1.0 {
+                    Self::ZERO
+                } else {
+                    Self::from_primitive(self.get().atanh())
+                }
+            }
+
+            /// Gamma function.
+            ///
+            /// The poles at zero and negative integers result in `MAX`. Overflow saturates to
+            /// `MAX` or `MIN`.
+            ///
+            /// Integers up to 23 give exact results. Other positive arguments use the Lanczos
+            /// approximation, whose relative error grows with the argument to about `1e-13` near
+            /// the overflow threshold. Arguments below 0.5 use the reflection formula, so their
+            /// error is about that of `gamma(1 - self)`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn gamma(self) -> Self {
+                let val = gamma_f64(self.get().into());
+                Self::from_primitive_with_underflow_sign(val as $base, || {
+                    if val.is_sign_negative() {
+                        Ordering::Less
+                    } else {
+                        Ordering::Greater
+                    }
+                })
+            }
+
+            /// Natural logarithm of the absolute value of the gamma function.
+            ///
+            /// The poles at zero and negative integers result in `MAX`.
+            ///
+            /// Computed by the Lanczos approximation, with an absolute error of about `1e-15` for
+            /// small arguments: `ln_gamma(1)` is not exactly zero.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn ln_gamma(self) -> Self {
+                Self::from_primitive(ln_gamma_f64(self.get().into()) as $base)
+            }
+
+            /// Error function.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn erf(self) -> Self {
+                Self::from_primitive_with_underflow_sign(erf_f64(self.get().into()) as $base, || {
+                    self.sign()
+                })
+            }
+
+            /// Complementary error function, `1 - erf(self)`.
+            ///
+            /// Accurate even when the result is small. The result is always positive, so values
+            /// that underflow result in `MIN_POSITIVE`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn erfc(self) -> Self {
+                Self::from_primitive_with_underflow_sign(erfc_f64(self.get().into()) as $base, || {
+                    Ordering::Greater
+                })
+            }
+
+            /// Euclidean division: `self.rem_euclid(rhs)` is non-negative.
+            ///
+            /// Division by zero results in `ZERO`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn div_euclid(self, rhs: Self) -> Self {
+                if rhs == Self::ZERO {
+                    Self::ZERO
+                } else {
+                    // Result is an integer: if underflow, it's 0.
+                    Self::from_primitive(self.get().div_euclid(rhs.get()))
+                }
+            }
+
+            /// Euclidean remainder, always non-negative.
+            ///
+            /// `x.rem_euclid(ZERO)` is `ZERO`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn rem_euclid(self, rhs: Self) -> Self {
+                let res = self.get().rem_euclid(rhs.get());
+                if res.is_nan() {
+                    // self.rem_euclid(0.0) = 0.0
+                    Self::ZERO
+                } else {
+                    // res == 0.0 iff exact multiple
+                    Self::from_primitive(res)
+                }
+            }
+
+            /// Angle normalized to `[ZERO, TAU)`.
+            #[inline]
+            pub fn wrap_to_2pi(self) -> Self {
+                let r = self % Self::TAU;
+                let r = if r.is_negative() { r + Self::TAU } else { r };
+                // Adding TAU to a tiny negative remainder can round up to TAU.
+                if r >= Self::TAU {
+                    Self::ZERO
+                } else {
+                    r
+                }
+            }
+
+            /// Angle normalized to `(-PI, PI]`.
+            #[inline]
+            pub fn wrap_to_pi(self) -> Self {
+                let r = self.wrap_to_2pi();
+                if r > Self::PI {
+                    r - Self::TAU
+                } else {
+                    r
+                }
+            }
+        }
+
+        impl Eq for $t {}
+
+        #[allow(clippy::derive_ord_xor_partial_ord)]
+        impl Ord for $t {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.partial_cmp(other).unwrap()
+            }
+        }
+
+        impl PartialEq<$base> for $t {
+            #[inline]
+            fn eq(&self, other: &$base) -> bool {
+                self.get() == *other
+            }
+        }
+
+        impl PartialEq<$t> for $base {
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                *self == other.get()
+            }
+        }
+
+        impl PartialOrd<$base> for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &$base) -> Option<Ordering> {
+                self.get().partial_cmp(other)
+            }
+        }
+
+        impl PartialOrd<$t> for $base {
+            #[inline]
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                self.partial_cmp(&other.get())
+            }
+        }
+
+        impl Default for $t {
+            #[inline]
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        #[allow(clippy::derived_hash_with_manual_eq)]
+        impl Hash for $t {
+            #[inline]
+            fn hash<H>(&self, state: &mut H)
+            where H: Hasher
+            {
+                self.get().to_bits().hash(state)
+            }
+        }
+
+        impl From<$t> for $base {
+            #[inline]
+            fn from(val: $t) -> Self {
+                val.get()
+            }
+        }
+
+        impl TryFrom<$base> for $t {
+            type Error = NanError;
+
+            #[inline]
+            fn try_from(val: $base) -> Result<$t, NanError> {
+                $t::new(val).ok_or(NanError)
+            }
+        }
+
+        /// The alternate form `{:#?}` shows `MAX` and `MIN` by name, since they are also the
+        /// results of saturated overflow.
+        impl fmt::Debug for $t {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                if f.alternate() && (*self == Self::MAX || *self == Self::MIN) {
+                    let name = if *self == Self::MAX { "MAX" } else { "MIN" };
+                    write!(f, "{}::{} (saturation sentinel)", stringify!($t), name)
+                } else {
+                    fmt::Debug::fmt(&self.get(), f)
+                }
+            }
+        }
+
+        impl_fmt!(Display for $t);
+        impl_fmt!(LowerExp for $t);
+        impl_fmt!(UpperExp for $t);
+
+        /// Accepts decimal and hexadecimal (`0x1.8p3`) notation.
+        ///
+        /// Values out of range, including `inf` and `infinity` in any case, saturate to `MAX` or
+        /// `MIN`. Values that round to zero result in `MIN_POSITIVE` or `MAX_NEGATIVE`, unless
+        /// they are exactly zero.
+        impl FromStr for $t {
+            type Err = ParseFiniteFloatError;
+
+            fn from_str(s: &str) -> Result<Self, ParseFiniteFloatError> {
+                if let Some(negative) = parse_infinity(s) {
+                    return Ok(if negative { Self::MIN } else { Self::MAX });
+                }
+                let val = match parse_hex_float(s) {
+                    Some(hex) => {
+                        let magnitude = Self::mul_pow2(hex.mantissa as $base, hex.exponent);
+                        if hex.negative {
+                            -magnitude
+                        } else {
+                            magnitude
+                        }
+                    }
+                    None => $base::from_str(s).map_err(ParseFiniteFloatError::Invalid)?,
+                };
+                if val.is_nan() {
+                    Err(ParseFiniteFloatError::Nan)
+                } else {
+                    Ok(Self::from_primitive_with_underflow_sign(val, || parse_sign_of_tiny_float(s)))
+                }
+            }
+        }
+
+        impl private::Sealed for $t {}
+
+        impl FiniteFloat for $t {
+            type Primitive = $base;
+
+            const MANTISSA_DIGITS: u32 = Self::MANTISSA_DIGITS;
+            const ZERO: Self = Self::ZERO;
+            const ONE: Self = Self::ONE;
+            const EPSILON: Self = Self::EPSILON;
+            const MIN: Self = Self::MIN;
+            const MAX: Self = Self::MAX;
+            const MIN_POSITIVE: Self = Self::MIN_POSITIVE;
+            const MAX_NEGATIVE: Self = Self::MAX_NEGATIVE;
+
+            #[inline]
+            fn new(val: $base) -> Option<Self> {
+                Self::new(val)
+            }
+
+            #[inline]
+            fn get(self) -> $base {
+                self.get()
+            }
+
+            #[inline]
+            fn is_positive(self) -> bool {
+                self.is_positive()
+            }
+
+            #[inline]
+            fn is_negative(self) -> bool {
+                self.is_negative()
+            }
+
+            #[inline]
+            fn is_zero(self) -> bool {
+                self.is_zero()
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                self.abs()
+            }
+
+            #[inline]
+            fn signum(self) -> Self {
+                self.signum()
+            }
+        }
+
+        impl Neg for $t {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self::from_primitive(-self.get())
+            }
+        }
+
+        impl Neg for &$t {
+            type Output = $t;
+
+            #[inline]
+            fn neg(self) -> $t {
+                (*self).neg()
+            }
+        }
+
+        impl Add for $t {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                // Result is 0 iff self == -rhs.
+                Self::from_primitive(self.get() + rhs.get())
+            }
+        }
+
+        impl_binary_op_alternatives!(Add for $t, add, AddAssign, add_assign);
+        impl_primitive_op!(Add for $t, $base, add, AddAssign, add_assign, |_, _| Ordering::Equal);
+
+        impl Sub for $t {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                // Result is 0 iff self == rhs.
+                Self::from_primitive(self.get() - rhs.get())
+            }
+        }
+
+        impl_binary_op_alternatives!(Sub for $t, sub, SubAssign, sub_assign);
+        impl_primitive_op!(Sub for $t, $base, sub, SubAssign, sub_assign, |_, _| Ordering::Equal);
+
+        impl Mul for $t {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                Self::from_primitive_with_underflow_sign(
+                    self.get() * rhs.get(),
+                    || multiply_signs(self.sign(), rhs.sign()))
+            }
+        }
+
+        impl_binary_op_alternatives!(Mul for $t, mul, MulAssign, mul_assign);
+        impl_primitive_op!(Mul for $t, $base, mul, MulAssign, mul_assign, multiply_signs);
+
+        impl Div for $t {
+            type Output = Self;
+
+            /// Division by zero saturates to `MAX` or `MIN` according to the sign of `self`.
+            ///
+            /// `ZERO / ZERO` is `ZERO`.
             #[inline]
             fn div(self, rhs: Self) -> Self {
                 let res = self.get() / rhs.get();
                 if res.is_nan() {
-                    // 0.0 / 0.0 = MAX
-                    Self::MAX
+                    // 0.0 / 0.0 = 0.0
+                    Self::ZERO
                 } else {
                     Self::from_primitive_with_underflow_sign(res,
                         || multiply_signs(self.sign(), rhs.sign()))
@@ -336,47 +1820,927 @@ macro_rules! impl_finite_float {
             }
         }
 
-        impl_binary_op_alternatives!(Div for $t, div, DivAssign, div_assign);
+        impl_binary_op_alternatives!(Div for $t, div, DivAssign, div_assign);
+        impl_primitive_op!(Div for $t, $base, div, DivAssign, div_assign, multiply_signs);
+
+        impl Rem for $t {
+            type Output = Self;
+
+            /// Truncated remainder: the result has the sign of `self`.
+            ///
+            /// `x % ZERO` is `ZERO`.
+            #[inline]
+            fn rem(self, rhs: Self) -> Self {
+                let res = self.get() % rhs.get();
+                if res.is_nan() {
+                    // x % 0.0 = 0.0
+                    Self::ZERO
+                } else {
+                    // res == 0 iff exact multiple
+                    Self::from_primitive(res)
+                }
+            }
+        }
+
+        impl_binary_op_alternatives!(Rem for $t, rem, RemAssign, rem_assign);
+
+        impl Sum for $t {
+            #[inline]
+            fn sum<I>(iter: I) -> Self
+            where I: Iterator<Item = Self>
+            {
+                iter.fold(Self::ZERO, Add::add)
+            }
+        }
+
+        impl<'a> Sum<&'a $t> for $t {
+            #[inline]
+            fn sum<I>(iter: I) -> Self
+            where I: Iterator<Item = &'a $t>
+            {
+                iter.fold(Self::ZERO, Add::add)
+            }
+        }
+
+        impl Product for $t {
+            #[inline]
+            fn product<I>(iter: I) -> Self
+            where I: Iterator<Item = Self>
+            {
+                iter.fold(Self(1.0), Mul::mul)
+            }
+        }
+
+        impl<'a> Product<&'a $t> for $t {
+            #[inline]
+            fn product<I>(iter: I) -> Self
+            where I: Iterator<Item = &'a $t>
+            {
+                iter.fold(Self(1.0), Mul::mul)
+            }
+        }
+
+    };
+}
+
+macro_rules! impl_from_int {
+    ($t:ident, $base:ident, $($int:ident),*) => {
+        $(
+            impl From<$int> for $t {
+                #[inline]
+                fn from(val: $int) -> Self {
+                    Self::from_primitive($base::from(val))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_try_into_int {
+    ($t:ident, $base:ident, $int:ident) => {
+        /// Rounds to the nearest integer, with ties rounded away from zero.
+        #[cfg(feature = "std")]
+        impl TryFrom<$t> for $int {
+            type Error = OutOfRangeError;
+
+            #[inline]
+            fn try_from(val: $t) -> Result<$int, OutOfRangeError> {
+                let rounded = val.get().round();
+                // The range is [-2^k, 2^k).
+                let min = $int::MIN as $base;
+                if rounded >= min && rounded < -min {
+                    Ok(rounded as $int)
+                } else {
+                    Err(OutOfRangeError)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_compensated_sum {
+    ($s:ident, $t:ident, $base:ident) => {
+        /// Compensated (Neumaier) summation accumulator.
+        ///
+        /// Much more accurate than adding the values one by one. If a partial sum overflows,
+        /// the result saturates to `MAX` or `MIN` in that direction, even if later values would
+        /// bring the exact sum back into range.
+        #[derive(Clone, Copy, Debug, Default)]
+        pub struct $s {
+            sum: $base,
+            compensation: $base,
+        }
+
+        impl $s {
+            /// Create an empty accumulator.
+            #[inline]
+            pub fn new() -> Self {
+                Self {
+                    sum: 0.0,
+                    compensation: 0.0,
+                }
+            }
+
+            /// Add a value.
+            #[inline]
+            pub fn add(&mut self, x: $t) {
+                let x = x.get();
+                let t = self.sum + x;
+                if t.is_infinite() {
+                    // Overflow: x is finite, so the sum stays infinite from now on.
+                    self.sum = t;
+                    return;
+                }
+                let abs_sum = if self.sum < 0.0 { -self.sum } else { self.sum };
+                let abs_x = if x < 0.0 { -x } else { x };
+                if abs_sum >= abs_x {
+                    self.compensation += (self.sum - t) + x;
+                } else {
+                    self.compensation += (x - t) + self.sum;
+                }
+                self.sum = t;
+            }
+
+            /// Return the sum.
+            #[inline]
+            pub fn finish(self) -> $t {
+                if self.sum.is_infinite() {
+                    $t::from_primitive(self.sum)
+                } else {
+                    $t::from_primitive(self.sum + self.compensation)
+                }
+            }
+        }
+
+        impl $t {
+            /// Dot product of two slices.
+            ///
+            /// Products are accumulated with compensated summation.
+            ///
+            /// Panics if the slices have different lengths.
+            pub fn dot(a: &[$t], b: &[$t]) -> $t {
+                assert_eq!(
+                    a.len(),
+                    b.len(),
+                    "dot product of slices of different lengths"
+                );
+                let mut sum = $s::new();
+                for (&x, &y) in a.iter().zip(b) {
+                    sum.add(x * y);
+                }
+                sum.finish()
+            }
+        }
+    };
+}
+
+impl_finite_float!(Float32, f32, u32);
+impl_finite_float!(Float64, f64, u64);
 
-        impl Rem for $t {
-            type Output = Self;
+impl_from_int!(Float32, f32, i8, u8, i16, u16);
+impl_from_int!(Float64, f64, i8, u8, i16, u16, i32, u32);
 
-            #[inline]
-            fn rem(self, rhs: Self) -> Self {
-                let res = self.get() % rhs.get();
-                if res.is_nan() {
-                    // x % 0.0 = 0.0
-                    Self::ZERO
+impl_try_into_int!(Float32, f32, i32);
+impl_try_into_int!(Float64, f64, i64);
+
+impl_compensated_sum!(Sum32, Float32, f32);
+impl_compensated_sum!(Sum64, Float64, f64);
+
+/// Number of independent accumulators in slice reductions.
+const LANES: usize = 8;
+
+/// Folds a slice into independent accumulators, which can be computed in parallel.
+#[inline]
+fn fold_lanes<S, F>(xs: &[Float64], init: S, f: F) -> [S; LANES]
+where
+    S: Copy,
+    F: Fn(S, f64) -> S,
+{
+    let mut acc = [init; LANES];
+    let mut chunks = xs.chunks_exact(LANES);
+    for chunk in &mut chunks {
+        for (a, &x) in acc.iter_mut().zip(chunk) {
+            *a = f(*a, x.get());
+        }
+    }
+    for (a, &x) in acc.iter_mut().zip(chunks.remainder()) {
+        *a = f(*a, x.get());
+    }
+    acc
+}
+
+/// Combines accumulators pairwise.
+#[inline]
+fn combine_lanes<S, F>(acc: [S; LANES], f: F) -> S
+where
+    S: Copy,
+    F: Fn(S, S) -> S,
+{
+    f(
+        f(f(acc[0], acc[1]), f(acc[2], acc[3])),
+        f(f(acc[4], acc[5]), f(acc[6], acc[7])),
+    )
+}
+
+/// Absolute value of a primitive float, by clearing the sign bit.
+#[inline]
+fn abs_f64(x: f64) -> f64 {
+    f64::from_bits(x.to_bits() & !(1 << 63))
+}
+
+/// Sum of a slice.
+///
+/// Uses several independent accumulators, which is faster than summing one by one.
+/// This may round differently than `Sum`. If `Sum` might saturate along the way, it is used
+/// instead, so saturated results are the same.
+pub fn sum_slice(xs: &[Float64]) -> Float64 {
+    // The sum, and a bound on the magnitude of every partial sum.
+    let (sum, abs_sum) = combine_lanes(
+        fold_lanes(xs, (0.0, 0.0), |(s, b), x| (s + x, b + abs_f64(x))),
+        |(s1, b1), (s2, b2)| (s1 + s2, b1 + b2),
+    );
+    if abs_sum <= f64::MAX / 2.0 {
+        Float64::from_primitive(sum)
+    } else {
+        xs.iter().sum()
+    }
+}
+
+/// Product of a slice.
+///
+/// Uses several independent accumulators, which is faster than multiplying one by one.
+/// This may round differently than `Product`. If `Product` might overflow or underflow along
+/// the way, it is used instead, so saturated results are the same.
+pub fn product_slice(xs: &[Float64]) -> Float64 {
+    // The product, and bounds on the magnitude of every partial product.
+    let (product, upper, lower) = combine_lanes(
+        fold_lanes(xs, (1.0, 1.0, 1.0), |(p, u, l), x| {
+            let a = abs_f64(x);
+            (p * x, u * a.max(1.0), l * a.min(1.0))
+        }),
+        |(p1, u1, l1), (p2, u2, l2)| (p1 * p2, u1 * u2, l1 * l2),
+    );
+    if upper <= f64::MAX / 2.0 && lower >= f64::MIN_POSITIVE * 2.0 {
+        Float64::from_primitive(product)
+    } else {
+        xs.iter().product()
+    }
+}
+
+/// Smallest element of a slice.
+///
+/// Returns `None` if the slice is empty.
+#[inline]
+pub fn min_element(xs: &[Float64]) -> Option<Float64> {
+    xs.iter().copied().min()
+}
+
+/// Largest element of a slice.
+///
+/// Returns `None` if the slice is empty.
+#[inline]
+pub fn max_element(xs: &[Float64]) -> Option<Float64> {
+    xs.iter().copied().max()
+}
+
+/// Arithmetic mean of a slice.
+///
+/// Returns `None` if the slice is empty.
+pub fn mean(xs: &[Float64]) -> Option<Float64> {
+    if xs.is_empty() {
+        return None;
+    }
+    let mut mean: f64 = 0.0;
+    for (i, &x) in xs.iter().enumerate() {
+        let x = x.get();
+        let n = (i + 1) as f64;
+        let delta = x - mean;
+        if delta.is_infinite() {
+            // n >= 2, so this doesn't overflow.
+            mean += x / n - mean / n;
+        } else {
+            mean += delta / n;
+        }
+    }
+    Some(Float64::from_primitive(mean))
+}
+
+/// Population variance of a slice, computed in one pass by Welford's algorithm.
+///
+/// Returns `None` if the slice is empty. Overflow saturates to `MAX`.
+pub fn variance(xs: &[Float64]) -> Option<Float64> {
+    if xs.is_empty() {
+        return None;
+    }
+    let mut mean: f64 = 0.0;
+    // Sum of squared differences from the mean.
+    let mut m2: f64 = 0.0;
+    for (i, &x) in xs.iter().enumerate() {
+        let x = x.get();
+        let n = (i + 1) as f64;
+        let delta = x - mean;
+        if delta.is_infinite() {
+            // n >= 2, so this doesn't overflow.
+            mean += x / n - mean / n;
+        } else {
+            mean += delta / n;
+        }
+        m2 += delta * (x - mean);
+    }
+    Some(Float64::from_primitive(m2 / xs.len() as f64))
+}
+
+/// Geometric mean of a slice of positive values, computed as `exp(mean(ln(x)))`.
+///
+/// Returns `None` if the slice is empty or contains a value that is not positive.
+#[cfg(feature = "std")]
+pub fn geometric_mean(xs: &[Float64]) -> Option<Float64> {
+    if xs.is_empty() {
+        return None;
+    }
+    let mut sum_ln: f64 = 0.0;
+    for &x in xs {
+        if !x.is_positive() {
+            return None;
+        }
+        sum_ln += x.get().ln();
+    }
+    let res = (sum_ln / xs.len() as f64).exp();
+    Some(Float64::from_primitive_with_underflow_sign(res, || {
+        Ordering::Greater
+    }))
+}
+
+impl From<Float32> for Float64 {
+    #[inline]
+    fn from(val: Float32) -> Self {
+        Float64::from_primitive(f64::from(val.get()))
+    }
+}
+
+/// Saturating conversion.
+impl From<Float64> for Float32 {
+    #[inline]
+    fn from(val: Float64) -> Self {
+        Float32::from_primitive_with_underflow_sign(val.get() as f32, || val.sign())
+    }
+}
+
+/// Compares exact values: `Float32` is widened to `f64` without rounding.
+impl PartialEq<Float64> for Float32 {
+    #[inline]
+    fn eq(&self, other: &Float64) -> bool {
+        self.as_f64() == other.get()
+    }
+}
+
+/// Compares exact values: `Float32` is widened to `f64` without rounding.
+impl PartialEq<Float32> for Float64 {
+    #[inline]
+    fn eq(&self, other: &Float32) -> bool {
+        self.get() == other.as_f64()
+    }
+}
+
+/// Compares exact values: `Float32` is widened to `f64` without rounding.
+impl PartialOrd<Float64> for Float32 {
+    #[inline]
+    fn partial_cmp(&self, other: &Float64) -> Option<Ordering> {
+        self.as_f64().partial_cmp(&other.get())
+    }
+}
+
+/// Compares exact values: `Float32` is widened to `f64` without rounding.
+impl PartialOrd<Float32> for Float64 {
+    #[inline]
+    fn partial_cmp(&self, other: &Float32) -> Option<Ordering> {
+        self.get().partial_cmp(&other.as_f64())
+    }
+}
+
+impl Float32 {
+    /// Return the value as `f32`.
+    #[inline]
+    pub const fn as_f32(self) -> f32 {
+        self.0
+    }
+
+    /// Return the value as `f64`.
+    ///
+    /// The conversion is exact.
+    #[inline]
+    pub fn as_f64(self) -> f64 {
+        self.0.into()
+    }
+
+    /// Convert from `f64` with the given rounding.
+    ///
+    /// NaN results in `ZERO`. Overflow saturates to `MAX` or `MIN`.
+    pub fn from_f64_round(val: f64, mode: RoundMode) -> Self {
+        if val.is_nan() {
+            return Self::ZERO;
+        }
+        // Nearest value, which is at most one step away from the result.
+        let nearest = Self::from(Float64::from_primitive(val));
+        let nearest_val = f64::from(nearest.get());
+        let round_up = |x: Self| if nearest_val < val { x.next_up() } else { x };
+        let round_down = |x: Self| if nearest_val > val { x.next_down() } else { x };
+        match mode {
+            RoundMode::Nearest => nearest,
+            RoundMode::TowardZero => {
+                if val >= 0.0 {
+                    round_down(nearest)
                 } else {
-                    // res == 0 iff exact multiple
-                    Self::from_primitive(res)
+                    round_up(nearest)
                 }
             }
+            RoundMode::Up => round_up(nearest),
+            RoundMode::Down => round_down(nearest),
         }
+    }
+}
 
-        impl_binary_op_alternatives!(Rem for $t, rem, RemAssign, rem_assign);
+impl Float64 {
+    /// Return the value as `f64`.
+    #[inline]
+    pub const fn as_f64(self) -> f64 {
+        self.0
+    }
 
-    };
+    /// Parse a number in base `radix`, which must be in `2..=16`:
+    /// `[+-] Digit* ( '.' Digit* )? ( [pP] [+-] DecimalDigit+ )?`
+    ///
+    /// The exponent after `p` is a power of `radix`. There must be at least one digit.
+    /// Out of range values and tiny values are handled as in `from_str`. Radices that are not a
+    /// power of two may round with an error of a few ulps.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseFiniteFloatError> {
+        if !(2..=16).contains(&radix) {
+            return Err(ParseFiniteFloatError::InvalidRadix(radix));
+        }
+        let (negative, digits) = split_sign(s);
+        if is_infinity(digits) {
+            return Ok(if negative { Self::MIN } else { Self::MAX });
+        }
+        if digits.eq_ignore_ascii_case("nan") {
+            return Err(ParseFiniteFloatError::Nan);
+        }
+        let parsed = parse_float_digits(negative, digits, radix, 1)
+            .ok_or(ParseFiniteFloatError::InvalidDigits)?;
+        let radix = f64::from(radix);
+        // Largest exactly representable power of the radix: radix^max_step.
+        let mut step = radix;
+        let mut max_step = 1;
+        while step * radix <= (1u64 << 53) as f64 {
+            step *= radix;
+            max_step += 1;
+        }
+        let mut magnitude = parsed.mantissa as f64;
+        let mut exponent = parsed.exponent;
+        while magnitude != 0.0 && !magnitude.is_infinite() && exponent > max_step {
+            magnitude *= step;
+            exponent -= max_step;
+        }
+        while magnitude != 0.0 && exponent < -max_step {
+            magnitude /= step;
+            exponent += max_step;
+        }
+        if magnitude != 0.0 && !magnitude.is_infinite() {
+            // Now |exponent| <= max_step, so this power is exact.
+            let rest = (0..exponent.abs()).fold(1.0, |p, _| p * radix);
+            if exponent > 0 {
+                magnitude *= rest;
+            } else {
+                magnitude /= rest;
+            }
+        }
+        let sign = if parsed.mantissa == 0 {
+            Ordering::Equal
+        } else if parsed.negative {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+        let val = if parsed.negative {
+            -magnitude
+        } else {
+            magnitude
+        };
+        Ok(Self::from_primitive_with_underflow_sign(val, || sign))
+    }
+}
+
+/// Closed interval `[lo, hi]` of `Float64` values.
+///
+/// Arithmetic rounds the endpoints outward, so that the result contains all exact results
+/// unless they overflow.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Interval64 {
+    lo: Float64,
+    hi: Float64,
+}
+
+impl Interval64 {
+    /// Create an interval.
+    ///
+    /// Panics if `lo > hi`.
+    #[inline]
+    pub fn new(lo: Float64, hi: Float64) -> Self {
+        assert!(lo <= hi, "interval with lo > hi");
+        Self { lo, hi }
+    }
+
+    /// Interval containing a single value.
+    #[inline]
+    pub fn point(val: Float64) -> Self {
+        Self { lo: val, hi: val }
+    }
+
+    /// Lower endpoint.
+    #[inline]
+    pub fn lo(self) -> Float64 {
+        self.lo
+    }
+
+    /// Upper endpoint.
+    #[inline]
+    pub fn hi(self) -> Float64 {
+        self.hi
+    }
+
+    /// `hi - lo`, rounded up.
+    #[inline]
+    pub fn width(self) -> Float64 {
+        let (hi, lo) = (self.hi.get(), self.lo.get());
+        let diff = hi - lo;
+        if diff.is_infinite() {
+            return Float64::MAX;
+        }
+        // Exact rounding error of `hi - lo` (TwoSum).
+        let b = diff - hi;
+        let err = (hi - (diff - b)) + (-lo - b);
+        let width = Float64::from_primitive(diff);
+        if err > 0.0 {
+            width.next_up()
+        } else {
+            width
+        }
+    }
+
+    /// Is `val` in the interval?
+    #[inline]
+    pub fn contains(self, val: Float64) -> bool {
+        self.lo <= val && val <= self.hi
+    }
+
+    /// Interval containing `[lo, hi]`, rounded outward by one step.
+    #[inline]
+    fn outward(lo: Float64, hi: Float64) -> Self {
+        Self {
+            lo: lo.next_down(),
+            hi: hi.next_up(),
+        }
+    }
+}
+
+impl Add for Interval64 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::outward(self.lo + rhs.lo, self.hi + rhs.hi)
+    }
+}
+
+impl Sub for Interval64 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::outward(self.lo - rhs.hi, self.hi - rhs.lo)
+    }
 }
 
-impl_finite_float!(Float32, f32);
-impl_finite_float!(Float64, f64);
+impl Mul for Interval64 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let a = self.lo * rhs.lo;
+        let b = self.lo * rhs.hi;
+        let c = self.hi * rhs.lo;
+        let d = self.hi * rhs.hi;
+        Self::outward(a.min(b).min(c.min(d)), a.max(b).max(c.max(d)))
+    }
+}
+
+impl Neg for Interval64 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+/// Dot product of two slices.
+///
+/// Same as `Float64::dot`.
+#[inline]
+pub fn dot(a: &[Float64], b: &[Float64]) -> Float64 {
+    Float64::dot(a, b)
+}
+
+/// Sort a slice in increasing order.
+///
+/// The order is total, so unlike sorting `f64` by `partial_cmp`, this is always well defined.
+#[inline]
+pub fn sort_floats(xs: &mut [Float64]) {
+    xs.sort_unstable();
+}
 
 /// Returns the sign of a floating point number that has rounded to zero.
 fn parse_sign_of_tiny_float(s: &str) -> Ordering {
-    // Only look at the prefix consisting of: [+-] Digit* ( '.' Digit* )?
-    let mut sign = Ordering::Greater;
-    for byte in s.as_bytes() {
+    // Only look at the prefix consisting of:
+    // [+-] ( "0x" HexDigit* ( '.' HexDigit* )? | Digit* ( '.' Digit* )? )
+    let (negative, s) = split_sign(s);
+    let sign = if negative {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    };
+    let (hex, digits) = match strip_hex_prefix(s) {
+        Some(digits) => (true, digits),
+        None => (false, s),
+    };
+    for byte in digits.as_bytes() {
         match byte {
-            b'-' => sign = Ordering::Less,
-            b'+' | b'0' | b'.' => {}
+            b'0' | b'.' => {}
             b'1'..=b'9' => return sign,
+            b'a'..=b'f' | b'A'..=b'F' if hex => return sign,
             _ => break,
         }
     }
     Ordering::Equal
 }
 
+/// Splits off an optional sign. Returns whether the number is negative.
+fn split_sign(s: &str) -> (bool, &str) {
+    match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    }
+}
+
+fn strip_hex_prefix(s: &str) -> Option<&str> {
+    if s.starts_with("0x") || s.starts_with("0X") {
+        Some(&s[2..])
+    } else {
+        None
+    }
+}
+
+/// Parses infinity: [+-] ( "inf" | "infinity" ), case insensitive.
+///
+/// Returns whether it is negative.
+fn parse_infinity(s: &str) -> Option<bool> {
+    let (negative, s) = split_sign(s);
+    if is_infinity(s) {
+        Some(negative)
+    } else {
+        None
+    }
+}
+
+/// Is `s` "inf" or "infinity", case insensitive?
+fn is_infinity(s: &str) -> bool {
+    s.eq_ignore_ascii_case("inf") || s.eq_ignore_ascii_case("infinity")
+}
+
+/// Float literal: `mantissa * base^exponent`.
+///
+/// The base is 2 for hexadecimal literals and the radix for `from_str_radix`.
+struct ParsedFloat {
+    negative: bool,
+    mantissa: u64,
+    exponent: i32,
+}
+
+/// Parses a hexadecimal float literal:
+/// [+-] "0x" HexDigit* ( '.' HexDigit* )? ( [pP] [+-] Digit+ )?
+///
+/// The exponent is a power of 2.
+fn parse_hex_float(s: &str) -> Option<ParsedFloat> {
+    let (negative, s) = split_sign(s);
+    let s = strip_hex_prefix(s)?;
+    parse_float_digits(negative, s, 16, 4)
+}
+
+/// Parses the unsigned part of a float literal in base `radix`:
+/// Digit* ( '.' Digit* )? ( [pP] [+-] DecimalDigit+ )?
+///
+/// Each digit is worth `digit_exponent` in the exponent, and the exponent after `p` is added
+/// as is.
+///
+/// There must be at least one digit. Digits that don't fit in the mantissa are folded into its
+/// lowest bit, which preserves correct rounding when the base is 2.
+fn parse_float_digits(
+    negative: bool,
+    s: &str,
+    radix: u32,
+    digit_exponent: i32,
+) -> Option<ParsedFloat> {
+    let bytes = s.as_bytes();
+    let max_mantissa = (u64::MAX - u64::from(radix - 1)) / u64::from(radix);
+    let mut mantissa: u64 = 0;
+    let mut exponent: i32 = 0;
+    let mut sticky = false;
+    let mut num_digits = 0;
+    let mut fraction = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'.' && !fraction {
+            fraction = true;
+        } else if let Some(digit) = char::from(bytes[i]).to_digit(radix) {
+            num_digits += 1;
+            if mantissa <= max_mantissa {
+                mantissa = mantissa * u64::from(radix) + u64::from(digit);
+                if fraction {
+                    exponent = exponent.saturating_sub(digit_exponent);
+                }
+            } else {
+                sticky |= digit != 0;
+                if !fraction {
+                    exponent = exponent.saturating_add(digit_exponent);
+                }
+            }
+        } else {
+            break;
+        }
+        i += 1;
+    }
+    if num_digits == 0 {
+        return None;
+    }
+    if i < bytes.len() {
+        if bytes[i] != b'p' && bytes[i] != b'P' {
+            return None;
+        }
+        let (exp_negative, digits) = split_sign(&s[i + 1..]);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let mut exp: i32 = 0;
+        for b in digits.bytes() {
+            exp = exp.saturating_mul(10).saturating_add(i32::from(b - b'0'));
+        }
+        exponent = if exp_negative {
+            exponent.saturating_sub(exp)
+        } else {
+            exponent.saturating_add(exp)
+        };
+    }
+    if sticky {
+        mantissa |= 1;
+    }
+    Some(ParsedFloat {
+        negative,
+        mantissa,
+        exponent,
+    })
+}
+
+/// Lanczos approximation coefficients for g = 7.
+#[cfg(feature = "std")]
+const LANCZOS_G: f64 = 7.0;
+
+#[cfg(feature = "std")]
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Lanczos sum for `x >= 0.5`.
+#[cfg(feature = "std")]
+fn lanczos_sum(x: f64) -> f64 {
+    let mut sum = LANCZOS_COEFFICIENTS[0];
+    for (i, &c) in LANCZOS_COEFFICIENTS.iter().enumerate().skip(1) {
+        sum += c / (x - 1.0 + i as f64);
+    }
+    sum
+}
+
+/// `sin(PI * x)`, accurate near integers.
+#[cfg(feature = "std")]
+fn sin_pi(x: f64) -> f64 {
+    // x - n is exact, and sin(PI * (x - n)) = (-1)^n sin(PI * x).
+    let n = x.round();
+    let s = (core::f64::consts::PI * (x - n)).sin();
+    if n % 2.0 == 0.0 {
+        s
+    } else {
+        -s
+    }
+}
+
+/// Gamma function. Poles result in infinity.
+#[cfg(feature = "std")]
+fn gamma_f64(x: f64) -> f64 {
+    if x <= 0.0 && x == x.floor() {
+        f64::INFINITY
+    } else if x < 0.5 {
+        // Reflection formula.
+        core::f64::consts::PI / (sin_pi(x) * gamma_f64(1.0 - x))
+    } else if x > 172.0 {
+        f64::INFINITY
+    } else if x == x.floor() {
+        // (x - 1)! as a product of integers. This is exact up to 22!, above that every
+        // multiplication rounds, with an error of a few ulps at the largest values.
+        let mut product = 1.0;
+        let mut i = 2.0;
+        while i < x {
+            product *= i;
+            i += 1.0;
+        }
+        product
+    } else {
+        let t = x + LANCZOS_G - 0.5;
+        // Split the power so that it doesn't overflow before being multiplied by exp(-t).
+        let half_power = t.powf((x - 0.5) / 2.0);
+        (2.0 * core::f64::consts::PI).sqrt() * lanczos_sum(x) * half_power * (-t).exp() * half_power
+    }
+}
+
+/// Natural logarithm of the absolute value of the gamma function. Poles result in infinity.
+#[cfg(feature = "std")]
+fn ln_gamma_f64(x: f64) -> f64 {
+    if x <= 0.0 && x == x.floor() {
+        f64::INFINITY
+    } else if x < 0.5 {
+        // Reflection formula.
+        (core::f64::consts::PI / sin_pi(x).abs()).ln() - ln_gamma_f64(1.0 - x)
+    } else {
+        let t = x + LANCZOS_G - 0.5;
+        0.5 * (2.0 * core::f64::consts::PI).ln() + (x - 0.5) * t.ln() - t + lanczos_sum(x).ln()
+    }
+}
+
+/// Error function.
+#[cfg(feature = "std")]
+fn erf_f64(x: f64) -> f64 {
+    if x.abs() < 2.0 {
+        // erf(x) = 2/sqrt(pi) exp(-x^2) sum_n 2^n x^(2n+1) / (1 * 3 * ... * (2n+1))
+        // All terms have the same sign, so there is no cancellation.
+        let mut term = x;
+        let mut sum = x;
+        let mut n = 0.0;
+        loop {
+            n += 1.0;
+            term *= 2.0 * x * x / (2.0 * n + 1.0);
+            let next = sum + term;
+            if next == sum {
+                break;
+            }
+            sum = next;
+        }
+        core::f64::consts::FRAC_2_SQRT_PI * (-x * x).exp() * sum
+    } else if x > 0.0 {
+        1.0 - erfc_f64(x)
+    } else {
+        erfc_f64(-x) - 1.0
+    }
+}
+
+/// Complementary error function.
+#[cfg(feature = "std")]
+fn erfc_f64(x: f64) -> f64 {
+    if x < 0.0 {
+        2.0 - erfc_f64(-x)
+    } else if x < 1.0 {
+        // erfc(x) > 0.15, so the cancellation loses at most a few bits.
+        1.0 - erf_f64(x)
+    } else {
+        // Continued fraction:
+        // erfc(x) = exp(-x^2) / sqrt(pi) / (x + (1/2) / (x + 1 / (x + (3/2) / (x + ...))))
+        // 200 terms are enough for x >= 1.
+        let mut denominator = x;
+        for k in (1..=200).rev() {
+            denominator = x + f64::from(k) / 2.0 / denominator;
+        }
+        0.5 * core::f64::consts::FRAC_2_SQRT_PI * (-x * x).exp() / denominator
+    }
+}
+
 #[inline]
 fn multiply_signs(lhs: Ordering, rhs: Ordering) -> Ordering {
     match lhs {
@@ -386,6 +2750,31 @@ fn multiply_signs(lhs: Ordering, rhs: Ordering) -> Ordering {
     }
 }
 
+/// Category of a finite float.
+///
+/// There is no subnormal category: subnormal numbers are rounded to `MIN_POSITIVE` or
+/// `MAX_NEGATIVE`, which are normal.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FiniteCategory {
+    /// Zero.
+    Zero,
+    /// Normal number.
+    Normal,
+}
+
+/// Rounding mode.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RoundMode {
+    /// Round to nearest, ties to even.
+    Nearest,
+    /// Round towards zero.
+    TowardZero,
+    /// Round towards `MAX`.
+    Up,
+    /// Round towards `MIN`.
+    Down,
+}
+
 /// Error indicating an attempt to convert a NaN to a finite float.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct NanError;
@@ -399,3 +2788,71 @@ impl fmt::Display for NanError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for NanError {}
+
+/// Error indicating an attempt to convert a finite float to an integer type that can't
+/// represent it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OutOfRangeError;
+
+impl fmt::Display for OutOfRangeError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "conversion from finite float to integer out of range")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfRangeError {}
+
+/// Error indicating that the result of an arithmetic operation is out of the finite range.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OverflowError;
+
+impl fmt::Display for OverflowError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "finite float arithmetic overflow")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OverflowError {}
+
+/// Error parsing a finite float.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseFiniteFloatError {
+    /// The string is not a valid floating point number.
+    Invalid(ParseFloatError),
+    /// The string represents NaN.
+    Nan,
+    /// The radix passed to `from_str_radix` is not in `2..=16`.
+    InvalidRadix(u32),
+    /// The string is not a valid number in the radix passed to `from_str_radix`.
+    InvalidDigits,
+}
+
+impl fmt::Display for ParseFiniteFloatError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseFiniteFloatError::Invalid(err) => fmt::Display::fmt(err, f),
+            ParseFiniteFloatError::Nan => write!(f, "NaN is not a finite float"),
+            ParseFiniteFloatError::InvalidRadix(radix) => {
+                write!(f, "radix {} is not in the range 2..=16", radix)
+            }
+            ParseFiniteFloatError::InvalidDigits => write!(f, "invalid float literal for radix"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFiniteFloatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseFiniteFloatError::Invalid(err) => Some(err),
+            ParseFiniteFloatError::Nan
+            | ParseFiniteFloatError::InvalidRadix(_)
+            | ParseFiniteFloatError::InvalidDigits => None,
+        }
+    }
+}
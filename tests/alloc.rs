@@ -0,0 +1,17 @@
+//! Tests of the APIs available with `alloc` but without `std`.
+//!
+//! Run with `cargo test --no-default-features --features alloc --test alloc`.
+
+use finite_float::{Float32, Float64};
+
+#[test]
+fn test_from_slice_sanitized() {
+    assert_eq!(
+        Float32::from_slice_sanitized(&[1.0, f32::NAN, f32::NEG_INFINITY]),
+        [Float32::ONE, Float32::ZERO, Float32::MIN]
+    );
+    assert_eq!(
+        Float64::from_slice_sanitized(&[1.0, f64::NAN, f64::NEG_INFINITY]),
+        [Float64::ONE, Float64::ZERO, Float64::MIN]
+    );
+}
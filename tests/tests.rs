@@ -1,6 +1,18 @@
-use finite_float::{Float32, Float64, NanError};
+use finite_float::{
+    dot, geometric_mean, max_element, mean, min_element, product_slice, sort_floats, sum_slice,
+    variance, FiniteCategory, FiniteFloat, Float32, Float64, Interval64, NanError, OutOfRangeError,
+    OverflowError, ParseFiniteFloatError, RoundMode, Sum32, Sum64,
+};
 
-use std::{cmp::Ordering, collections::HashMap, convert::TryFrom};
+use std::{cmp::Ordering, collections::HashMap, convert::TryFrom, error::Error};
+
+/// xorshift64 pseudo-random generator.
+fn xorshift64(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
 
 #[test]
 fn test_constants() {
@@ -159,9 +171,18 @@ fn test_parse() {
     assert!("foo".parse::<Float32>().is_err());
     assert!("foo".parse::<Float64>().is_err());
 
+    assert!(matches!(
+        "foo".parse::<Float32>(),
+        Err(ParseFiniteFloatError::Invalid(_))
+    ));
+    assert!(matches!(
+        "foo".parse::<Float64>(),
+        Err(ParseFiniteFloatError::Invalid(_))
+    ));
+
     // NaN doesn't parse.
-    assert!("NaN".parse::<Float32>().is_err());
-    assert!("NaN".parse::<Float64>().is_err());
+    assert_eq!("NaN".parse::<Float32>(), Err(ParseFiniteFloatError::Nan));
+    assert_eq!("NaN".parse::<Float64>(), Err(ParseFiniteFloatError::Nan));
 
     // Normal.
     assert_eq!("3.14".parse::<Float32>().unwrap().get(), 3.14);
@@ -603,8 +624,10 @@ fn test_div() {
     assert_eq!(Float64::new(-3.0).unwrap() / Float64::ZERO, Float64::MIN);
 
     // Zero / zero.
-    assert_eq!(Float32::ZERO / Float32::ZERO, Float32::MAX);
-    assert_eq!(Float64::ZERO / Float64::ZERO, Float64::MAX);
+    assert_eq!(Float32::ZERO / Float32::ZERO, Float32::ZERO);
+    assert_eq!(Float64::ZERO / Float64::ZERO, Float64::ZERO);
+    assert!((Float32::ZERO / Float32::ZERO).get().is_sign_positive());
+    assert!((Float64::ZERO / Float64::ZERO).get().is_sign_positive());
 
     // Overflow.
     assert_eq!(Float32::MAX / Float32::MIN_POSITIVE, Float32::MAX);
@@ -857,25 +880,25 @@ fn test_div_euclid() {
     // Non-zero / zero.
     assert_eq!(
         Float32::new(3.0).unwrap().div_euclid(Float32::ZERO),
-        Float32::MAX
+        Float32::ZERO
     );
     assert_eq!(
         Float32::new(-3.0).unwrap().div_euclid(Float32::ZERO),
-        Float32::MIN
+        Float32::ZERO
     );
 
     assert_eq!(
         Float64::new(3.0).unwrap().div_euclid(Float64::ZERO),
-        Float64::MAX
+        Float64::ZERO
     );
     assert_eq!(
         Float64::new(-3.0).unwrap().div_euclid(Float64::ZERO),
-        Float64::MIN
+        Float64::ZERO
     );
 
     // Zero / zero.
-    assert_eq!(Float32::ZERO.div_euclid(Float32::ZERO), Float32::MAX);
-    assert_eq!(Float64::ZERO.div_euclid(Float64::ZERO), Float64::MAX);
+    assert_eq!(Float32::ZERO.div_euclid(Float32::ZERO), Float32::ZERO);
+    assert_eq!(Float64::ZERO.div_euclid(Float64::ZERO), Float64::ZERO);
 
     // Overflow.
     assert_eq!(Float32::MAX.div_euclid(Float32::MIN_POSITIVE), Float32::MAX);
@@ -979,3 +1002,4091 @@ fn test_rem_euclid() {
         Float64::ZERO
     );
 }
+
+#[test]
+fn test_signum() {
+    assert_eq!(
+        Float32::new(3.0).unwrap().signum(),
+        Float32::new(1.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-3.0).unwrap().signum(),
+        Float32::new(-1.0).unwrap()
+    );
+    assert_eq!(Float32::ZERO.signum(), Float32::ZERO);
+    assert!(Float32::ZERO.signum().get().is_sign_positive());
+    assert_eq!(Float32::MIN.signum(), Float32::new(-1.0).unwrap());
+    assert_eq!(Float32::MAX_NEGATIVE.signum(), Float32::new(-1.0).unwrap());
+
+    assert_eq!(
+        Float64::new(3.0).unwrap().signum(),
+        Float64::new(1.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-3.0).unwrap().signum(),
+        Float64::new(-1.0).unwrap()
+    );
+    assert_eq!(Float64::ZERO.signum(), Float64::ZERO);
+    assert!(Float64::ZERO.signum().get().is_sign_positive());
+    assert_eq!(Float64::MIN.signum(), Float64::new(-1.0).unwrap());
+    assert_eq!(Float64::MAX_NEGATIVE.signum(), Float64::new(-1.0).unwrap());
+}
+
+#[test]
+fn test_sqrt() {
+    assert_eq!(
+        Float32::new(4.0).unwrap().sqrt(),
+        Float32::new(2.0).unwrap()
+    );
+    assert_eq!(Float32::ZERO.sqrt(), Float32::ZERO);
+    assert_eq!(Float32::MAX_NEGATIVE.sqrt(), Float32::ZERO);
+    assert_eq!(Float32::MIN.sqrt(), Float32::ZERO);
+    assert_eq!(Float32::MAX.sqrt().get(), f32::MAX.sqrt());
+
+    assert_eq!(
+        Float64::new(4.0).unwrap().sqrt(),
+        Float64::new(2.0).unwrap()
+    );
+    assert_eq!(Float64::ZERO.sqrt(), Float64::ZERO);
+    assert_eq!(Float64::MAX_NEGATIVE.sqrt(), Float64::ZERO);
+    assert_eq!(Float64::MIN.sqrt(), Float64::ZERO);
+    assert_eq!(Float64::MAX.sqrt().get(), f64::MAX.sqrt());
+}
+
+#[test]
+fn test_min_max() {
+    let a = Float32::new(-3.0).unwrap();
+    let b = Float32::new(2.0).unwrap();
+    assert_eq!(a.min(b), a);
+    assert_eq!(b.min(a), a);
+    assert_eq!(a.max(b), b);
+    assert_eq!(b.max(a), b);
+    assert_eq!(Float32::MIN.min(Float32::MAX), Float32::MIN);
+    assert_eq!(Float32::MIN.max(Float32::MAX), Float32::MAX);
+    assert_eq!(Float32::ZERO.min(Float32::ZERO), Float32::ZERO);
+
+    let a = Float64::new(-3.0).unwrap();
+    let b = Float64::new(2.0).unwrap();
+    assert_eq!(a.min(b), a);
+    assert_eq!(b.min(a), a);
+    assert_eq!(a.max(b), b);
+    assert_eq!(b.max(a), b);
+    assert_eq!(Float64::MIN.min(Float64::MAX), Float64::MIN);
+    assert_eq!(Float64::MIN.max(Float64::MAX), Float64::MAX);
+    assert_eq!(Float64::ZERO.min(Float64::ZERO), Float64::ZERO);
+}
+
+#[test]
+fn test_clamp() {
+    let lo = Float32::new(-1.0).unwrap();
+    let hi = Float32::new(2.0).unwrap();
+    assert_eq!(Float32::MIN.clamp(lo, hi), lo);
+    assert_eq!(Float32::new(0.5).unwrap().clamp(lo, hi).get(), 0.5);
+    assert_eq!(Float32::MAX.clamp(lo, hi), hi);
+    assert_eq!(Float32::ZERO.clamp(hi, hi), hi);
+
+    let lo = Float64::new(-1.0).unwrap();
+    let hi = Float64::new(2.0).unwrap();
+    assert_eq!(Float64::MIN.clamp(lo, hi), lo);
+    assert_eq!(Float64::new(0.5).unwrap().clamp(lo, hi).get(), 0.5);
+    assert_eq!(Float64::MAX.clamp(lo, hi), hi);
+    assert_eq!(Float64::ZERO.clamp(hi, hi), hi);
+}
+
+#[test]
+fn test_recip() {
+    assert_eq!(
+        Float32::new(2.0).unwrap().recip(),
+        Float32::new(0.5).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-4.0).unwrap().recip(),
+        Float32::new(-0.25).unwrap()
+    );
+    assert_eq!(Float32::ZERO.recip(), Float32::MAX);
+    assert_eq!(Float32::MAX.recip(), Float32::MIN_POSITIVE);
+    assert_eq!(Float32::MIN.recip(), Float32::MAX_NEGATIVE);
+    assert_eq!(Float32::MIN_POSITIVE.recip().get(), 1.0 / f32::MIN_POSITIVE);
+
+    assert_eq!(
+        Float64::new(2.0).unwrap().recip(),
+        Float64::new(0.5).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-4.0).unwrap().recip(),
+        Float64::new(-0.25).unwrap()
+    );
+    assert_eq!(Float64::ZERO.recip(), Float64::MAX);
+    assert_eq!(Float64::MAX.recip(), Float64::MIN_POSITIVE);
+    assert_eq!(Float64::MIN.recip(), Float64::MAX_NEGATIVE);
+    assert_eq!(Float64::MIN_POSITIVE.recip().get(), 1.0 / f64::MIN_POSITIVE);
+}
+
+#[test]
+fn test_mul_add() {
+    // Normal.
+    assert_eq!(
+        Float32::new(2.0)
+            .unwrap()
+            .mul_add(Float32::new(3.0).unwrap(), Float32::new(4.0).unwrap()),
+        Float32::new(10.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(2.0)
+            .unwrap()
+            .mul_add(Float64::new(3.0).unwrap(), Float64::new(4.0).unwrap()),
+        Float64::new(10.0).unwrap()
+    );
+
+    // Single rounding.
+    let x = Float32::new(1.0 + f32::EPSILON).unwrap();
+    let y = Float32::new(-1.0 - 2.0 * f32::EPSILON).unwrap();
+    assert_eq!(x * x + y, Float32::ZERO);
+    assert_eq!(x.mul_add(x, y), Float32::EPSILON * Float32::EPSILON);
+
+    let x = Float64::new(1.0 + f64::EPSILON).unwrap();
+    let y = Float64::new(-1.0 - 2.0 * f64::EPSILON).unwrap();
+    assert_eq!(x * x + y, Float64::ZERO);
+    assert_eq!(x.mul_add(x, y), Float64::EPSILON * Float64::EPSILON);
+
+    // No intermediate overflow.
+    assert_eq!(
+        Float32::MAX.mul_add(Float32::new(2.0).unwrap(), Float32::MIN),
+        Float32::MAX
+    );
+    assert_eq!(
+        Float64::MAX.mul_add(Float64::new(2.0).unwrap(), Float64::MIN),
+        Float64::MAX
+    );
+
+    // Overflow.
+    assert_eq!(
+        Float32::MAX.mul_add(Float32::MAX, Float32::MIN),
+        Float32::MAX
+    );
+    assert_eq!(
+        Float32::MIN.mul_add(Float32::MAX, Float32::MAX),
+        Float32::MIN
+    );
+    assert_eq!(
+        Float64::MAX.mul_add(Float64::MAX, Float64::MIN),
+        Float64::MAX
+    );
+    assert_eq!(
+        Float64::MIN.mul_add(Float64::MAX, Float64::MAX),
+        Float64::MIN
+    );
+}
+
+#[test]
+fn test_powi() {
+    // Normal.
+    assert_eq!(
+        Float32::new(-2.0).unwrap().powi(3),
+        Float32::new(-8.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(2.0).unwrap().powi(-2),
+        Float32::new(0.25).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-2.0).unwrap().powi(3),
+        Float64::new(-8.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(2.0).unwrap().powi(-2),
+        Float64::new(0.25).unwrap()
+    );
+
+    // Zero exponent.
+    assert_eq!(Float32::ZERO.powi(0), Float32::new(1.0).unwrap());
+    assert_eq!(Float32::MIN.powi(0), Float32::new(1.0).unwrap());
+    assert_eq!(Float64::ZERO.powi(0), Float64::new(1.0).unwrap());
+    assert_eq!(Float64::MIN.powi(0), Float64::new(1.0).unwrap());
+
+    // Zero base.
+    assert_eq!(Float32::ZERO.powi(3), Float32::ZERO);
+    assert_eq!(Float32::ZERO.powi(-1), Float32::MAX);
+    assert_eq!(Float32::ZERO.powi(-2), Float32::MAX);
+    assert_eq!(Float64::ZERO.powi(3), Float64::ZERO);
+    assert_eq!(Float64::ZERO.powi(-1), Float64::MAX);
+    assert_eq!(Float64::ZERO.powi(-2), Float64::MAX);
+
+    // Overflow.
+    assert_eq!(Float32::MAX.powi(2), Float32::MAX);
+    assert_eq!(Float32::MIN.powi(2), Float32::MAX);
+    assert_eq!(Float32::MIN.powi(3), Float32::MIN);
+    assert_eq!(Float64::MAX.powi(2), Float64::MAX);
+    assert_eq!(Float64::MIN.powi(2), Float64::MAX);
+    assert_eq!(Float64::MIN.powi(3), Float64::MIN);
+
+    // Underflow.
+    assert_eq!(Float32::MIN_POSITIVE.powi(2), Float32::MIN_POSITIVE);
+    assert_eq!(Float32::MAX_NEGATIVE.powi(2), Float32::MIN_POSITIVE);
+    assert_eq!(Float32::MAX_NEGATIVE.powi(3), Float32::MAX_NEGATIVE);
+    assert_eq!(Float64::MIN_POSITIVE.powi(2), Float64::MIN_POSITIVE);
+    assert_eq!(Float64::MAX_NEGATIVE.powi(2), Float64::MIN_POSITIVE);
+    assert_eq!(Float64::MAX_NEGATIVE.powi(3), Float64::MAX_NEGATIVE);
+}
+
+#[test]
+fn test_powf() {
+    // Normal.
+    assert_eq!(
+        Float32::new(2.0).unwrap().powf(Float32::new(10.0).unwrap()),
+        Float32::new(1024.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-2.0).unwrap().powf(Float32::new(3.0).unwrap()),
+        Float32::new(-8.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(2.0).unwrap().powf(Float64::new(10.0).unwrap()),
+        Float64::new(1024.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-2.0).unwrap().powf(Float64::new(3.0).unwrap()),
+        Float64::new(-8.0).unwrap()
+    );
+
+    // Negative base, non-integer exponent.
+    assert_eq!(
+        Float32::new(-8.0).unwrap().powf(Float32::new(0.5).unwrap()),
+        Float32::ZERO
+    );
+    assert_eq!(
+        Float64::new(-8.0).unwrap().powf(Float64::new(0.5).unwrap()),
+        Float64::ZERO
+    );
+
+    // Zero base.
+    assert_eq!(
+        Float32::ZERO.powf(Float32::ZERO),
+        Float32::new(1.0).unwrap()
+    );
+    assert_eq!(Float32::ZERO.powf(Float32::MIN), Float32::MAX);
+    assert_eq!(
+        Float64::ZERO.powf(Float64::ZERO),
+        Float64::new(1.0).unwrap()
+    );
+    assert_eq!(Float64::ZERO.powf(Float64::MIN), Float64::MAX);
+
+    // Overflow.
+    let two = Float32::new(2.0).unwrap();
+    let three = Float32::new(3.0).unwrap();
+    assert_eq!(Float32::MAX.powf(two), Float32::MAX);
+    assert_eq!(Float32::MIN.powf(two), Float32::MAX);
+    assert_eq!(Float32::MIN.powf(three), Float32::MIN);
+    let two = Float64::new(2.0).unwrap();
+    let three = Float64::new(3.0).unwrap();
+    assert_eq!(Float64::MAX.powf(two), Float64::MAX);
+    assert_eq!(Float64::MIN.powf(two), Float64::MAX);
+    assert_eq!(Float64::MIN.powf(three), Float64::MIN);
+
+    // Underflow.
+    let two = Float32::new(2.0).unwrap();
+    let three = Float32::new(3.0).unwrap();
+    assert_eq!(Float32::MIN_POSITIVE.powf(two), Float32::MIN_POSITIVE);
+    assert_eq!(Float32::MAX_NEGATIVE.powf(two), Float32::MIN_POSITIVE);
+    assert_eq!(Float32::MAX_NEGATIVE.powf(three), Float32::MAX_NEGATIVE);
+    let two = Float64::new(2.0).unwrap();
+    let three = Float64::new(3.0).unwrap();
+    assert_eq!(Float64::MIN_POSITIVE.powf(two), Float64::MIN_POSITIVE);
+    assert_eq!(Float64::MAX_NEGATIVE.powf(two), Float64::MIN_POSITIVE);
+    assert_eq!(Float64::MAX_NEGATIVE.powf(three), Float64::MAX_NEGATIVE);
+}
+
+#[test]
+fn test_from_int() {
+    assert_eq!(Float32::from(i8::MIN).get(), -128.0);
+    assert_eq!(Float32::from(i8::MAX).get(), 127.0);
+    assert_eq!(Float32::from(u8::MAX).get(), 255.0);
+    assert_eq!(Float32::from(i16::MIN).get(), -32768.0);
+    assert_eq!(Float32::from(i16::MAX).get(), 32767.0);
+    assert_eq!(Float32::from(u16::MAX).get(), 65535.0);
+    assert_eq!(Float32::from(0u8), Float32::ZERO);
+
+    assert_eq!(Float64::from(i8::MIN).get(), -128.0);
+    assert_eq!(Float64::from(i8::MAX).get(), 127.0);
+    assert_eq!(Float64::from(u8::MAX).get(), 255.0);
+    assert_eq!(Float64::from(i16::MIN).get(), -32768.0);
+    assert_eq!(Float64::from(i16::MAX).get(), 32767.0);
+    assert_eq!(Float64::from(u16::MAX).get(), 65535.0);
+    assert_eq!(Float64::from(i32::MIN).get(), -2147483648.0);
+    assert_eq!(Float64::from(i32::MAX).get(), 2147483647.0);
+    assert_eq!(Float64::from(u32::MAX).get(), 4294967295.0);
+    assert_eq!(Float64::from(0u8), Float64::ZERO);
+}
+
+#[test]
+fn test_try_into_int() {
+    assert_eq!(i32::try_from(Float32::new(3.0).unwrap()), Ok(3));
+    assert_eq!(i32::try_from(Float32::new(-3.0).unwrap()), Ok(-3));
+    assert_eq!(i32::try_from(Float32::new(2.5).unwrap()), Ok(3));
+    assert_eq!(i32::try_from(Float32::new(-2.5).unwrap()), Ok(-3));
+    assert_eq!(i32::try_from(Float32::new(2.4).unwrap()), Ok(2));
+    assert_eq!(i32::try_from(Float32::ZERO), Ok(0));
+    assert_eq!(
+        i32::try_from(Float32::new(-2147483648.0).unwrap()),
+        Ok(i32::MIN)
+    );
+    assert_eq!(
+        i32::try_from(Float32::new(2147483648.0).unwrap()),
+        Err(OutOfRangeError)
+    );
+    assert_eq!(i32::try_from(Float32::MAX), Err(OutOfRangeError));
+    assert_eq!(i32::try_from(Float32::MIN), Err(OutOfRangeError));
+
+    assert_eq!(i64::try_from(Float64::new(3.0).unwrap()), Ok(3));
+    assert_eq!(i64::try_from(Float64::new(-3.0).unwrap()), Ok(-3));
+    assert_eq!(i64::try_from(Float64::new(2.5).unwrap()), Ok(3));
+    assert_eq!(i64::try_from(Float64::new(-2.5).unwrap()), Ok(-3));
+    assert_eq!(i64::try_from(Float64::new(2.4).unwrap()), Ok(2));
+    assert_eq!(i64::try_from(Float64::ZERO), Ok(0));
+    assert_eq!(
+        i64::try_from(Float64::new(-9223372036854775808.0).unwrap()),
+        Ok(i64::MIN)
+    );
+    assert_eq!(
+        i64::try_from(Float64::new(9223372036854775808.0).unwrap()),
+        Err(OutOfRangeError)
+    );
+    assert_eq!(i64::try_from(Float64::MAX), Err(OutOfRangeError));
+    assert_eq!(i64::try_from(Float64::MIN), Err(OutOfRangeError));
+}
+
+#[test]
+fn test_sum() {
+    let v: Vec<Float32> = vec![];
+    assert_eq!(v.iter().sum::<Float32>(), Float32::ZERO);
+    let v: Vec<Float32> = [1.0, 2.0, 3.5]
+        .iter()
+        .map(|&x| Float32::new(x).unwrap())
+        .collect();
+    assert_eq!(v.iter().sum::<Float32>(), Float32::new(6.5).unwrap());
+    assert_eq!(
+        v.iter().copied().sum::<Float32>(),
+        Float32::new(6.5).unwrap()
+    );
+    let v = [Float32::MAX; 3];
+    assert_eq!(v.iter().sum::<Float32>(), Float32::MAX);
+
+    let v: Vec<Float64> = vec![];
+    assert_eq!(v.iter().sum::<Float64>(), Float64::ZERO);
+    let v: Vec<Float64> = [1.0, 2.0, 3.5]
+        .iter()
+        .map(|&x| Float64::new(x).unwrap())
+        .collect();
+    assert_eq!(v.iter().sum::<Float64>(), Float64::new(6.5).unwrap());
+    assert_eq!(
+        v.iter().copied().sum::<Float64>(),
+        Float64::new(6.5).unwrap()
+    );
+    let v = [Float64::MAX; 3];
+    assert_eq!(v.iter().sum::<Float64>(), Float64::MAX);
+}
+
+#[test]
+fn test_product() {
+    let v: Vec<Float32> = vec![];
+    assert_eq!(v.iter().product::<Float32>(), Float32::new(1.0).unwrap());
+    let v: Vec<Float32> = [2.0, -3.0, 0.5]
+        .iter()
+        .map(|&x| Float32::new(x).unwrap())
+        .collect();
+    assert_eq!(v.iter().product::<Float32>(), Float32::new(-3.0).unwrap());
+    assert_eq!(
+        v.iter().copied().product::<Float32>(),
+        Float32::new(-3.0).unwrap()
+    );
+    let v = [Float32::MAX, Float32::MAX, Float32::MIN];
+    assert_eq!(v.iter().product::<Float32>(), Float32::MIN);
+    let v = [Float32::MAX_NEGATIVE, Float32::MIN_POSITIVE];
+    assert_eq!(v.iter().product::<Float32>(), Float32::MAX_NEGATIVE);
+    let v = [Float32::MAX, Float32::ZERO, Float32::MIN];
+    assert_eq!(v.iter().product::<Float32>(), Float32::ZERO);
+
+    let v: Vec<Float64> = vec![];
+    assert_eq!(v.iter().product::<Float64>(), Float64::new(1.0).unwrap());
+    let v: Vec<Float64> = [2.0, -3.0, 0.5]
+        .iter()
+        .map(|&x| Float64::new(x).unwrap())
+        .collect();
+    assert_eq!(v.iter().product::<Float64>(), Float64::new(-3.0).unwrap());
+    assert_eq!(
+        v.iter().copied().product::<Float64>(),
+        Float64::new(-3.0).unwrap()
+    );
+    let v = [Float64::MAX, Float64::MAX, Float64::MIN];
+    assert_eq!(v.iter().product::<Float64>(), Float64::MIN);
+    let v = [Float64::MAX_NEGATIVE, Float64::MIN_POSITIVE];
+    assert_eq!(v.iter().product::<Float64>(), Float64::MAX_NEGATIVE);
+    let v = [Float64::MAX, Float64::ZERO, Float64::MIN];
+    assert_eq!(v.iter().product::<Float64>(), Float64::ZERO);
+}
+
+#[test]
+#[allow(clippy::approx_constant)]
+fn test_widen() {
+    assert_eq!(Float64::from(Float32::ZERO), Float64::ZERO);
+    assert!(Float64::from(Float32::ZERO).get().is_sign_positive());
+    assert_eq!(
+        Float64::from(Float32::new(3.14).unwrap()).get(),
+        f64::from(3.14f32)
+    );
+    assert_eq!(Float64::from(Float32::MAX).get(), f64::from(f32::MAX));
+    assert!(Float64::from(Float32::MAX) < Float64::MAX);
+    assert_eq!(
+        Float64::from(Float32::MIN_POSITIVE).get(),
+        f64::from(f32::MIN_POSITIVE)
+    );
+}
+
+#[test]
+fn test_narrow() {
+    assert_eq!(Float32::from(Float64::ZERO), Float32::ZERO);
+    assert_eq!(
+        Float32::from(Float64::new(2.5).unwrap()),
+        Float32::new(2.5).unwrap()
+    );
+    assert_eq!(Float32::from(Float64::from(Float32::MAX)), Float32::MAX);
+    assert_eq!(
+        Float32::from(Float64::from(Float32::MIN_POSITIVE)),
+        Float32::MIN_POSITIVE
+    );
+
+    // Overflow.
+    assert_eq!(Float32::from(Float64::MAX), Float32::MAX);
+    assert_eq!(Float32::from(Float64::MIN), Float32::MIN);
+
+    // Subnormal.
+    assert_eq!(
+        Float32::from(Float64::new(1e-40).unwrap()),
+        Float32::MIN_POSITIVE
+    );
+    assert_eq!(
+        Float32::from(Float64::new(-1e-40).unwrap()),
+        Float32::MAX_NEGATIVE
+    );
+
+    // Underflow.
+    assert_eq!(Float32::from(Float64::MIN_POSITIVE), Float32::MIN_POSITIVE);
+    assert_eq!(Float32::from(Float64::MAX_NEGATIVE), Float32::MAX_NEGATIVE);
+}
+
+#[test]
+fn test_abs() {
+    assert_eq!(
+        Float32::new(-3.0).unwrap().abs(),
+        Float32::new(3.0).unwrap()
+    );
+    assert_eq!(Float32::new(3.0).unwrap().abs(), Float32::new(3.0).unwrap());
+    assert_eq!(Float32::ZERO.abs(), Float32::ZERO);
+    assert_eq!(Float32::MIN.abs(), Float32::MAX);
+
+    assert_eq!(
+        Float64::new(-3.0).unwrap().abs(),
+        Float64::new(3.0).unwrap()
+    );
+    assert_eq!(Float64::new(3.0).unwrap().abs(), Float64::new(3.0).unwrap());
+    assert_eq!(Float64::ZERO.abs(), Float64::ZERO);
+    assert_eq!(Float64::MIN.abs(), Float64::MAX);
+}
+
+#[test]
+fn test_copysign() {
+    let pos = Float32::new(3.0).unwrap();
+    let neg = Float32::new(-3.0).unwrap();
+    let two = Float32::new(2.0).unwrap();
+    let minus_two = Float32::new(-2.0).unwrap();
+    assert_eq!(pos.copysign(two), pos);
+    assert_eq!(pos.copysign(minus_two), neg);
+    assert_eq!(pos.copysign(Float32::ZERO), pos);
+    assert_eq!(neg.copysign(two), pos);
+    assert_eq!(neg.copysign(minus_two), neg);
+    assert_eq!(neg.copysign(Float32::ZERO), pos);
+    assert_eq!(Float32::ZERO.copysign(minus_two), Float32::ZERO);
+    assert!(Float32::ZERO.copysign(minus_two).get().is_sign_positive());
+    assert_eq!(Float32::MIN.copysign(Float32::MIN_POSITIVE), Float32::MAX);
+
+    let pos = Float64::new(3.0).unwrap();
+    let neg = Float64::new(-3.0).unwrap();
+    let two = Float64::new(2.0).unwrap();
+    let minus_two = Float64::new(-2.0).unwrap();
+    assert_eq!(pos.copysign(two), pos);
+    assert_eq!(pos.copysign(minus_two), neg);
+    assert_eq!(pos.copysign(Float64::ZERO), pos);
+    assert_eq!(neg.copysign(two), pos);
+    assert_eq!(neg.copysign(minus_two), neg);
+    assert_eq!(neg.copysign(Float64::ZERO), pos);
+    assert_eq!(Float64::ZERO.copysign(minus_two), Float64::ZERO);
+    assert!(Float64::ZERO.copysign(minus_two).get().is_sign_positive());
+    assert_eq!(Float64::MIN.copysign(Float64::MIN_POSITIVE), Float64::MAX);
+}
+
+#[test]
+fn test_hypot() {
+    assert_eq!(
+        Float32::new(3.0)
+            .unwrap()
+            .hypot(Float32::new(-4.0).unwrap()),
+        Float32::new(5.0).unwrap()
+    );
+    assert_eq!(Float32::ZERO.hypot(Float32::ZERO), Float32::ZERO);
+    let half_max = Float32::MAX / Float32::new(2.0).unwrap();
+    assert_eq!(half_max * half_max, Float32::MAX);
+    assert!(half_max.hypot(half_max) < Float32::MAX);
+    assert!(half_max.hypot(half_max) > half_max);
+    assert_eq!(Float32::MAX.hypot(Float32::MIN), Float32::MAX);
+
+    assert_eq!(
+        Float64::new(3.0)
+            .unwrap()
+            .hypot(Float64::new(-4.0).unwrap()),
+        Float64::new(5.0).unwrap()
+    );
+    assert_eq!(Float64::ZERO.hypot(Float64::ZERO), Float64::ZERO);
+    let half_max = Float64::MAX / Float64::new(2.0).unwrap();
+    assert_eq!(half_max * half_max, Float64::MAX);
+    assert!(half_max.hypot(half_max) < Float64::MAX);
+    assert!(half_max.hypot(half_max) > half_max);
+    assert_eq!(Float64::MAX.hypot(Float64::MIN), Float64::MAX);
+}
+
+#[test]
+fn test_rounding() {
+    let x = Float32::new(-2.5).unwrap();
+    assert_eq!(x.floor(), Float32::new(-3.0).unwrap());
+    assert_eq!(x.ceil(), Float32::new(-2.0).unwrap());
+    assert_eq!(x.round(), Float32::new(-3.0).unwrap());
+    assert_eq!(x.trunc(), Float32::new(-2.0).unwrap());
+    assert_eq!(x.fract(), Float32::new(-0.5).unwrap());
+    assert_eq!(x.trunc() + x.fract(), x);
+
+    let x = Float32::new(2.75).unwrap();
+    assert_eq!(x.floor() + x.fract(), x);
+
+    let x = Float32::new(-0.25).unwrap();
+    assert!(x.ceil().get().is_sign_positive());
+    assert!(x.round().get().is_sign_positive());
+    assert!(x.trunc().get().is_sign_positive());
+
+    let x = Float32::new(7.0).unwrap();
+    assert_eq!(x.floor(), x);
+    assert_eq!(x.ceil(), x);
+    assert_eq!(x.round(), x);
+    assert_eq!(x.trunc(), x);
+    assert_eq!(x.fract(), Float32::ZERO);
+    assert_eq!(Float32::MIN.fract(), Float32::ZERO);
+    assert!(Float32::MIN.fract().get().is_sign_positive());
+    assert_eq!(Float32::MIN.floor(), Float32::MIN);
+
+    let x = Float64::new(-2.5).unwrap();
+    assert_eq!(x.floor(), Float64::new(-3.0).unwrap());
+    assert_eq!(x.ceil(), Float64::new(-2.0).unwrap());
+    assert_eq!(x.round(), Float64::new(-3.0).unwrap());
+    assert_eq!(x.trunc(), Float64::new(-2.0).unwrap());
+    assert_eq!(x.fract(), Float64::new(-0.5).unwrap());
+    assert_eq!(x.trunc() + x.fract(), x);
+
+    let x = Float64::new(2.75).unwrap();
+    assert_eq!(x.floor() + x.fract(), x);
+
+    let x = Float64::new(-0.25).unwrap();
+    assert!(x.ceil().get().is_sign_positive());
+    assert!(x.round().get().is_sign_positive());
+    assert!(x.trunc().get().is_sign_positive());
+
+    let x = Float64::new(7.0).unwrap();
+    assert_eq!(x.floor(), x);
+    assert_eq!(x.ceil(), x);
+    assert_eq!(x.round(), x);
+    assert_eq!(x.trunc(), x);
+    assert_eq!(x.fract(), Float64::ZERO);
+    assert_eq!(Float64::MIN.fract(), Float64::ZERO);
+    assert!(Float64::MIN.fract().get().is_sign_positive());
+    assert_eq!(Float64::MIN.floor(), Float64::MIN);
+}
+
+#[test]
+fn test_exp_ln() {
+    let one = Float32::new(1.0).unwrap();
+    assert_eq!(Float32::ZERO.exp(), one);
+    assert_eq!(Float32::MAX.exp(), Float32::MAX);
+    assert_eq!(Float32::MIN.exp(), Float32::MIN_POSITIVE);
+    assert_eq!(one.ln(), Float32::ZERO);
+    assert_eq!(Float32::ZERO.ln(), Float32::MIN);
+    assert_eq!(Float32::new(-2.0).unwrap().ln(), Float32::ZERO);
+    assert_eq!(Float32::MAX_NEGATIVE.ln(), Float32::ZERO);
+
+    let one = Float64::new(1.0).unwrap();
+    assert_eq!(Float64::ZERO.exp(), one);
+    assert_eq!(Float64::MAX.exp(), Float64::MAX);
+    assert_eq!(Float64::MIN.exp(), Float64::MIN_POSITIVE);
+    assert_eq!(one.ln(), Float64::ZERO);
+    assert_eq!(Float64::ZERO.ln(), Float64::MIN);
+    assert_eq!(Float64::new(-2.0).unwrap().ln(), Float64::ZERO);
+    assert_eq!(Float64::MAX_NEGATIVE.ln(), Float64::ZERO);
+}
+
+#[test]
+fn test_log() {
+    let one = Float32::new(1.0).unwrap();
+    let three = Float32::new(3.0).unwrap();
+    assert_eq!(Float32::new(8.0).unwrap().log2(), three);
+    assert_eq!(Float32::new(1000.0).unwrap().log10(), three);
+    assert_eq!(
+        Float32::new(81.0).unwrap().log(three),
+        Float32::new(4.0).unwrap()
+    );
+    assert_eq!(Float32::ZERO.log2(), Float32::MIN);
+    assert_eq!(Float32::ZERO.log10(), Float32::MIN);
+    assert_eq!(Float32::ZERO.log(three), Float32::MIN);
+    assert_eq!(Float32::ZERO.log(Float32::new(0.5).unwrap()), Float32::MAX);
+    assert_eq!(Float32::new(-8.0).unwrap().log2(), Float32::ZERO);
+    assert_eq!(Float32::new(-8.0).unwrap().log10(), Float32::ZERO);
+    assert_eq!(Float32::new(-8.0).unwrap().log(three), Float32::ZERO);
+    assert_eq!(three.log(one), Float32::ZERO);
+    assert_eq!(one.log(one), Float32::ZERO);
+    assert_eq!(three.log(Float32::ZERO), Float32::ZERO);
+    assert_eq!(three.log(-three), Float32::ZERO);
+
+    let one = Float64::new(1.0).unwrap();
+    let three = Float64::new(3.0).unwrap();
+    assert_eq!(Float64::new(8.0).unwrap().log2(), three);
+    assert_eq!(Float64::new(1000.0).unwrap().log10(), three);
+    assert_eq!(
+        Float64::new(81.0).unwrap().log(three),
+        Float64::new(4.0).unwrap()
+    );
+    assert_eq!(Float64::ZERO.log2(), Float64::MIN);
+    assert_eq!(Float64::ZERO.log10(), Float64::MIN);
+    assert_eq!(Float64::ZERO.log(three), Float64::MIN);
+    assert_eq!(Float64::ZERO.log(Float64::new(0.5).unwrap()), Float64::MAX);
+    assert_eq!(Float64::new(-8.0).unwrap().log2(), Float64::ZERO);
+    assert_eq!(Float64::new(-8.0).unwrap().log10(), Float64::ZERO);
+    assert_eq!(Float64::new(-8.0).unwrap().log(three), Float64::ZERO);
+    assert_eq!(three.log(one), Float64::ZERO);
+    assert_eq!(one.log(one), Float64::ZERO);
+    assert_eq!(three.log(Float64::ZERO), Float64::ZERO);
+    assert_eq!(three.log(-three), Float64::ZERO);
+}
+
+#[test]
+fn test_exp2_exp_m1_ln_1p() {
+    let x = Float32::new(1e-5).unwrap();
+    assert_eq!(
+        Float32::new(3.0).unwrap().exp2(),
+        Float32::new(8.0).unwrap()
+    );
+    assert_eq!(Float32::MAX.exp2(), Float32::MAX);
+    assert_eq!(Float32::MIN.exp2(), Float32::MIN_POSITIVE);
+    assert!((x.exp_m1() - x).abs() < (x.exp() - Float32::new(1.0).unwrap() - x).abs());
+    assert_eq!(Float32::ZERO.exp_m1(), Float32::ZERO);
+    assert_eq!(Float32::MAX.exp_m1(), Float32::MAX);
+    assert_eq!(Float32::MIN.exp_m1(), Float32::new(-1.0).unwrap());
+    assert!((x.ln_1p() - x).abs() < ((Float32::new(1.0).unwrap() + x).ln() - x).abs());
+    assert_eq!(Float32::ZERO.ln_1p(), Float32::ZERO);
+    assert_eq!(Float32::new(-1.0).unwrap().ln_1p(), Float32::MIN);
+    assert_eq!(Float32::new(-2.0).unwrap().ln_1p(), Float32::MIN);
+
+    let x = Float64::new(1e-10).unwrap();
+    assert_eq!(
+        Float64::new(3.0).unwrap().exp2(),
+        Float64::new(8.0).unwrap()
+    );
+    assert_eq!(Float64::MAX.exp2(), Float64::MAX);
+    assert_eq!(Float64::MIN.exp2(), Float64::MIN_POSITIVE);
+    assert!((x.exp_m1() - x).abs() < (x.exp() - Float64::new(1.0).unwrap() - x).abs());
+    assert_eq!(Float64::ZERO.exp_m1(), Float64::ZERO);
+    assert_eq!(Float64::MAX.exp_m1(), Float64::MAX);
+    assert_eq!(Float64::MIN.exp_m1(), Float64::new(-1.0).unwrap());
+    assert!((x.ln_1p() - x).abs() < ((Float64::new(1.0).unwrap() + x).ln() - x).abs());
+    assert_eq!(Float64::ZERO.ln_1p(), Float64::ZERO);
+    assert_eq!(Float64::new(-1.0).unwrap().ln_1p(), Float64::MIN);
+    assert_eq!(Float64::new(-2.0).unwrap().ln_1p(), Float64::MIN);
+}
+
+#[test]
+fn test_trig() {
+    assert_eq!(Float32::ZERO.sin(), Float32::ZERO);
+    assert_eq!(Float32::ZERO.cos(), Float32::new(1.0).unwrap());
+    assert_eq!(Float32::ZERO.tan(), Float32::ZERO);
+    let x = Float32::new(std::f32::consts::FRAC_PI_2).unwrap();
+    assert!(x.tan().abs() > Float32::new(1e6).unwrap());
+    assert!(x.tan() <= Float32::MAX);
+    assert!((-x).tan() >= Float32::MIN);
+    assert!(Float32::MAX.sin().abs() <= Float32::new(1.0).unwrap());
+
+    assert_eq!(Float64::ZERO.sin(), Float64::ZERO);
+    assert_eq!(Float64::ZERO.cos(), Float64::new(1.0).unwrap());
+    assert_eq!(Float64::ZERO.tan(), Float64::ZERO);
+    let x = Float64::new(std::f64::consts::FRAC_PI_2).unwrap();
+    assert!(x.tan().abs() > Float64::new(1e15).unwrap());
+    assert!(x.tan() <= Float64::MAX);
+    assert!((-x).tan() >= Float64::MIN);
+    assert!(Float64::MAX.sin().abs() <= Float64::new(1.0).unwrap());
+}
+
+#[test]
+fn test_inverse_trig() {
+    let one = Float32::new(1.0).unwrap();
+    let two = Float32::new(2.0).unwrap();
+    assert_eq!(one.asin().get(), std::f32::consts::FRAC_PI_2);
+    assert_eq!(Float32::ZERO.acos().get(), std::f32::consts::FRAC_PI_2);
+    assert_eq!(one.atan().get(), std::f32::consts::FRAC_PI_4);
+    assert_eq!(two.asin(), one.asin());
+    assert_eq!((-two).asin(), (-one).asin());
+    assert_eq!(two.acos(), Float32::ZERO);
+    assert_eq!((-two).acos().get(), std::f32::consts::PI);
+    assert_eq!(Float32::MAX.atan().get(), std::f32::consts::FRAC_PI_2);
+
+    let one = Float64::new(1.0).unwrap();
+    let two = Float64::new(2.0).unwrap();
+    assert_eq!(one.asin().get(), std::f64::consts::FRAC_PI_2);
+    assert_eq!(Float64::ZERO.acos().get(), std::f64::consts::FRAC_PI_2);
+    assert_eq!(one.atan().get(), std::f64::consts::FRAC_PI_4);
+    assert_eq!(two.asin(), one.asin());
+    assert_eq!((-two).asin(), (-one).asin());
+    assert_eq!(two.acos(), Float64::ZERO);
+    assert_eq!((-two).acos().get(), std::f64::consts::PI);
+    assert_eq!(Float64::MAX.atan().get(), std::f64::consts::FRAC_PI_2);
+}
+
+#[test]
+fn test_atan2() {
+    use std::f32::consts::{FRAC_PI_4, PI};
+    let one = Float32::new(1.0).unwrap();
+    assert_eq!(one.atan2(one).get(), FRAC_PI_4);
+    assert_eq!(one.atan2(-one).get(), 3.0 * FRAC_PI_4);
+    assert_eq!((-one).atan2(-one).get(), -3.0 * FRAC_PI_4);
+    assert_eq!((-one).atan2(one).get(), -FRAC_PI_4);
+    assert_eq!(Float32::ZERO.atan2(-one).get(), PI);
+    assert_eq!(Float32::ZERO.atan2(one), Float32::ZERO);
+    assert_eq!(Float32::ZERO.atan2(Float32::ZERO), Float32::ZERO);
+    assert!(Float32::ZERO.atan2(Float32::ZERO).get().is_sign_positive());
+    assert_eq!(
+        Float32::MIN_POSITIVE.atan2(Float32::MAX),
+        Float32::MIN_POSITIVE
+    );
+    assert_eq!(
+        Float32::MAX_NEGATIVE.atan2(Float32::MAX),
+        Float32::MAX_NEGATIVE
+    );
+
+    use std::f64::consts::{FRAC_PI_4 as FRAC_PI_4_64, PI as PI_64};
+    let one = Float64::new(1.0).unwrap();
+    assert_eq!(one.atan2(one).get(), FRAC_PI_4_64);
+    assert_eq!(one.atan2(-one).get(), 3.0 * FRAC_PI_4_64);
+    assert_eq!((-one).atan2(-one).get(), -3.0 * FRAC_PI_4_64);
+    assert_eq!((-one).atan2(one).get(), -FRAC_PI_4_64);
+    assert_eq!(Float64::ZERO.atan2(-one).get(), PI_64);
+    assert_eq!(Float64::ZERO.atan2(one), Float64::ZERO);
+    assert_eq!(Float64::ZERO.atan2(Float64::ZERO), Float64::ZERO);
+    assert!(Float64::ZERO.atan2(Float64::ZERO).get().is_sign_positive());
+    assert_eq!(
+        Float64::MIN_POSITIVE.atan2(Float64::MAX),
+        Float64::MIN_POSITIVE
+    );
+    assert_eq!(
+        Float64::MAX_NEGATIVE.atan2(Float64::MAX),
+        Float64::MAX_NEGATIVE
+    );
+}
+
+#[test]
+fn test_inverse_hyperbolic() {
+    let one = Float32::new(1.0).unwrap();
+    let two = Float32::new(2.0).unwrap();
+    let half = Float32::new(0.5).unwrap();
+    assert_eq!(Float32::ZERO.asinh(), Float32::ZERO);
+    assert_eq!(one.asinh().get(), 1.0f32.asinh());
+    assert_eq!((-one).asinh().get(), (-1.0f32).asinh());
+    assert_eq!(one.acosh(), Float32::ZERO);
+    assert_eq!(two.acosh().get(), 2.0f32.acosh());
+    assert_eq!(half.acosh(), Float32::ZERO);
+    assert_eq!(Float32::MIN.acosh(), Float32::ZERO);
+    assert_eq!(Float32::ZERO.atanh(), Float32::ZERO);
+    assert_eq!(half.atanh().get(), 0.5f32.atanh());
+    assert_eq!(one.atanh(), Float32::MAX);
+    assert_eq!((-one).atanh(), Float32::MIN);
+    assert_eq!(two.atanh(), Float32::ZERO);
+    assert_eq!((-two).atanh(), Float32::ZERO);
+
+    let one = Float64::new(1.0).unwrap();
+    let two = Float64::new(2.0).unwrap();
+    let half = Float64::new(0.5).unwrap();
+    assert_eq!(Float64::ZERO.asinh(), Float64::ZERO);
+    assert_eq!(one.asinh().get(), 1.0f64.asinh());
+    assert_eq!((-one).asinh().get(), (-1.0f64).asinh());
+    assert_eq!(one.acosh(), Float64::ZERO);
+    assert_eq!(two.acosh().get(), 2.0f64.acosh());
+    assert_eq!(half.acosh(), Float64::ZERO);
+    assert_eq!(Float64::MIN.acosh(), Float64::ZERO);
+    assert_eq!(Float64::ZERO.atanh(), Float64::ZERO);
+    assert_eq!(half.atanh().get(), 0.5f64.atanh());
+    assert_eq!(one.atanh(), Float64::MAX);
+    assert_eq!((-one).atanh(), Float64::MIN);
+    assert_eq!(two.atanh(), Float64::ZERO);
+    assert_eq!((-two).atanh(), Float64::ZERO);
+}
+
+#[test]
+fn test_cbrt() {
+    assert_eq!(
+        Float32::new(27.0).unwrap().cbrt(),
+        Float32::new(3.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-8.0).unwrap().cbrt(),
+        Float32::new(-2.0).unwrap()
+    );
+    assert_eq!(Float32::ZERO.cbrt(), Float32::ZERO);
+    assert_eq!(Float32::MIN.cbrt().get(), f32::MIN.cbrt());
+
+    assert_eq!(
+        Float64::new(27.0).unwrap().cbrt(),
+        Float64::new(3.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-8.0).unwrap().cbrt(),
+        Float64::new(-2.0).unwrap()
+    );
+    assert_eq!(Float64::ZERO.cbrt(), Float64::ZERO);
+    assert_eq!(Float64::MIN.cbrt().get(), f64::MIN.cbrt());
+}
+
+#[test]
+fn test_math_constants() {
+    assert_eq!(Float32::PI.get(), std::f32::consts::PI);
+    assert_eq!(Float32::TAU.get(), std::f32::consts::TAU);
+    assert_eq!(Float32::FRAC_PI_2.get(), std::f32::consts::FRAC_PI_2);
+    assert_eq!(Float32::FRAC_PI_3.get(), std::f32::consts::FRAC_PI_3);
+    assert_eq!(Float32::FRAC_PI_4.get(), std::f32::consts::FRAC_PI_4);
+    assert_eq!(Float32::FRAC_PI_6.get(), std::f32::consts::FRAC_PI_6);
+    assert_eq!(Float32::FRAC_PI_8.get(), std::f32::consts::FRAC_PI_8);
+    assert_eq!(Float32::FRAC_1_PI.get(), std::f32::consts::FRAC_1_PI);
+    assert_eq!(Float32::FRAC_2_PI.get(), std::f32::consts::FRAC_2_PI);
+    assert_eq!(
+        Float32::FRAC_2_SQRT_PI.get(),
+        std::f32::consts::FRAC_2_SQRT_PI
+    );
+    assert_eq!(Float32::SQRT_2.get(), std::f32::consts::SQRT_2);
+    assert_eq!(
+        Float32::FRAC_1_SQRT_2.get(),
+        std::f32::consts::FRAC_1_SQRT_2
+    );
+    assert_eq!(Float32::E.get(), std::f32::consts::E);
+    assert_eq!(Float32::LOG2_E.get(), std::f32::consts::LOG2_E);
+    assert_eq!(Float32::LOG2_10.get(), std::f32::consts::LOG2_10);
+    assert_eq!(Float32::LOG10_E.get(), std::f32::consts::LOG10_E);
+    assert_eq!(Float32::LOG10_2.get(), std::f32::consts::LOG10_2);
+    assert_eq!(Float32::LN_2.get(), std::f32::consts::LN_2);
+    assert_eq!(Float32::LN_10.get(), std::f32::consts::LN_10);
+
+    assert_eq!(Float64::PI.get(), std::f64::consts::PI);
+    assert_eq!(Float64::TAU.get(), std::f64::consts::TAU);
+    assert_eq!(Float64::FRAC_PI_2.get(), std::f64::consts::FRAC_PI_2);
+    assert_eq!(Float64::FRAC_PI_3.get(), std::f64::consts::FRAC_PI_3);
+    assert_eq!(Float64::FRAC_PI_4.get(), std::f64::consts::FRAC_PI_4);
+    assert_eq!(Float64::FRAC_PI_6.get(), std::f64::consts::FRAC_PI_6);
+    assert_eq!(Float64::FRAC_PI_8.get(), std::f64::consts::FRAC_PI_8);
+    assert_eq!(Float64::FRAC_1_PI.get(), std::f64::consts::FRAC_1_PI);
+    assert_eq!(Float64::FRAC_2_PI.get(), std::f64::consts::FRAC_2_PI);
+    assert_eq!(
+        Float64::FRAC_2_SQRT_PI.get(),
+        std::f64::consts::FRAC_2_SQRT_PI
+    );
+    assert_eq!(Float64::SQRT_2.get(), std::f64::consts::SQRT_2);
+    assert_eq!(
+        Float64::FRAC_1_SQRT_2.get(),
+        std::f64::consts::FRAC_1_SQRT_2
+    );
+    assert_eq!(Float64::E.get(), std::f64::consts::E);
+    assert_eq!(Float64::LOG2_E.get(), std::f64::consts::LOG2_E);
+    assert_eq!(Float64::LOG2_10.get(), std::f64::consts::LOG2_10);
+    assert_eq!(Float64::LOG10_E.get(), std::f64::consts::LOG10_E);
+    assert_eq!(Float64::LOG10_2.get(), std::f64::consts::LOG10_2);
+    assert_eq!(Float64::LN_2.get(), std::f64::consts::LN_2);
+    assert_eq!(Float64::LN_10.get(), std::f64::consts::LN_10);
+}
+
+#[test]
+fn test_checked() {
+    let two = Float32::new(2.0).unwrap();
+    let three = Float32::new(3.0).unwrap();
+    assert_eq!(two.checked_add(three), Some(Float32::new(5.0).unwrap()));
+    assert_eq!(Float32::MAX.checked_add(Float32::MAX), None);
+    assert_eq!(Float32::MIN.checked_add(Float32::MIN), None);
+    assert_eq!(Float32::MAX.checked_add(Float32::MIN), Some(Float32::ZERO));
+    assert_eq!(two.checked_sub(three), Some(Float32::new(-1.0).unwrap()));
+    assert_eq!(Float32::MAX.checked_sub(Float32::MIN), None);
+    assert_eq!(Float32::MAX.checked_sub(Float32::MAX), Some(Float32::ZERO));
+    assert_eq!(two.checked_mul(three), Some(Float32::new(6.0).unwrap()));
+    assert_eq!(Float32::MAX.checked_mul(two), None);
+    assert_eq!(Float32::MAX.checked_mul(-two), None);
+    assert_eq!(
+        Float32::MAX.checked_mul(Float32::new(1.0).unwrap()),
+        Some(Float32::MAX)
+    );
+    assert_eq!(
+        Float32::MIN_POSITIVE.checked_mul(-Float32::MIN_POSITIVE),
+        Some(Float32::MAX_NEGATIVE)
+    );
+
+    let two = Float64::new(2.0).unwrap();
+    let three = Float64::new(3.0).unwrap();
+    assert_eq!(two.checked_add(three), Some(Float64::new(5.0).unwrap()));
+    assert_eq!(Float64::MAX.checked_add(Float64::MAX), None);
+    assert_eq!(Float64::MIN.checked_add(Float64::MIN), None);
+    assert_eq!(Float64::MAX.checked_add(Float64::MIN), Some(Float64::ZERO));
+    assert_eq!(two.checked_sub(three), Some(Float64::new(-1.0).unwrap()));
+    assert_eq!(Float64::MAX.checked_sub(Float64::MIN), None);
+    assert_eq!(Float64::MAX.checked_sub(Float64::MAX), Some(Float64::ZERO));
+    assert_eq!(two.checked_mul(three), Some(Float64::new(6.0).unwrap()));
+    assert_eq!(Float64::MAX.checked_mul(two), None);
+    assert_eq!(Float64::MAX.checked_mul(-two), None);
+    assert_eq!(
+        Float64::MAX.checked_mul(Float64::new(1.0).unwrap()),
+        Some(Float64::MAX)
+    );
+    assert_eq!(
+        Float64::MIN_POSITIVE.checked_mul(-Float64::MIN_POSITIVE),
+        Some(Float64::MAX_NEGATIVE)
+    );
+}
+
+#[test]
+fn test_overflowing() {
+    let two = Float32::new(2.0).unwrap();
+    let three = Float32::new(3.0).unwrap();
+    assert_eq!(
+        two.overflowing_add(three),
+        (Float32::new(5.0).unwrap(), false)
+    );
+    assert_eq!(
+        Float32::MAX.overflowing_add(Float32::MAX),
+        (Float32::MAX, true)
+    );
+    assert_eq!(
+        Float32::MAX.overflowing_add(Float32::MIN),
+        (Float32::ZERO, false)
+    );
+    assert_eq!(
+        Float32::MIN.overflowing_sub(Float32::MAX),
+        (Float32::MIN, true)
+    );
+    assert_eq!(
+        two.overflowing_sub(three),
+        (Float32::new(-1.0).unwrap(), false)
+    );
+    assert_eq!(Float32::MAX.overflowing_mul(-two), (Float32::MIN, true));
+    assert_eq!(
+        Float32::MIN_POSITIVE.overflowing_mul(Float32::MIN_POSITIVE),
+        (Float32::MIN_POSITIVE, false)
+    );
+    assert_eq!(
+        Float32::MAX_NEGATIVE.overflowing_mul(Float32::MIN_POSITIVE),
+        (Float32::MAX_NEGATIVE, false)
+    );
+    assert_eq!(
+        two.overflowing_mul(three),
+        (Float32::new(6.0).unwrap(), false)
+    );
+
+    let two = Float64::new(2.0).unwrap();
+    let three = Float64::new(3.0).unwrap();
+    assert_eq!(
+        two.overflowing_add(three),
+        (Float64::new(5.0).unwrap(), false)
+    );
+    assert_eq!(
+        Float64::MAX.overflowing_add(Float64::MAX),
+        (Float64::MAX, true)
+    );
+    assert_eq!(
+        Float64::MAX.overflowing_add(Float64::MIN),
+        (Float64::ZERO, false)
+    );
+    assert_eq!(
+        Float64::MIN.overflowing_sub(Float64::MAX),
+        (Float64::MIN, true)
+    );
+    assert_eq!(
+        two.overflowing_sub(three),
+        (Float64::new(-1.0).unwrap(), false)
+    );
+    assert_eq!(Float64::MAX.overflowing_mul(-two), (Float64::MIN, true));
+    assert_eq!(
+        Float64::MIN_POSITIVE.overflowing_mul(Float64::MIN_POSITIVE),
+        (Float64::MIN_POSITIVE, false)
+    );
+    assert_eq!(
+        Float64::MAX_NEGATIVE.overflowing_mul(Float64::MIN_POSITIVE),
+        (Float64::MAX_NEGATIVE, false)
+    );
+    assert_eq!(
+        two.overflowing_mul(three),
+        (Float64::new(6.0).unwrap(), false)
+    );
+}
+
+#[test]
+fn test_next_up_down() {
+    assert_eq!(Float32::ZERO.next_up(), Float32::MIN_POSITIVE);
+    assert_eq!(Float32::ZERO.next_down(), Float32::MAX_NEGATIVE);
+    assert_eq!(Float32::MIN_POSITIVE.next_down(), Float32::ZERO);
+    assert_eq!(Float32::MAX_NEGATIVE.next_up(), Float32::ZERO);
+    assert_eq!(Float32::MAX.next_up(), Float32::MAX);
+    assert_eq!(Float32::MIN.next_down(), Float32::MIN);
+    assert_eq!(Float32::MAX.next_down().next_up(), Float32::MAX);
+    assert_eq!(Float32::MIN.next_up().next_down(), Float32::MIN);
+    let one = Float32::new(1.0).unwrap();
+    assert_eq!(one.next_up(), one + Float32::EPSILON);
+    assert_eq!(one.next_down().get(), 1.0 - f32::EPSILON / 2.0);
+    assert_eq!((-one).next_down(), -one - Float32::EPSILON);
+    assert_eq!((-one).next_up().get(), -1.0 + f32::EPSILON / 2.0);
+
+    assert_eq!(Float64::ZERO.next_up(), Float64::MIN_POSITIVE);
+    assert_eq!(Float64::ZERO.next_down(), Float64::MAX_NEGATIVE);
+    assert_eq!(Float64::MIN_POSITIVE.next_down(), Float64::ZERO);
+    assert_eq!(Float64::MAX_NEGATIVE.next_up(), Float64::ZERO);
+    assert_eq!(Float64::MAX.next_up(), Float64::MAX);
+    assert_eq!(Float64::MIN.next_down(), Float64::MIN);
+    assert_eq!(Float64::MAX.next_down().next_up(), Float64::MAX);
+    assert_eq!(Float64::MIN.next_up().next_down(), Float64::MIN);
+    let one = Float64::new(1.0).unwrap();
+    assert_eq!(one.next_up(), one + Float64::EPSILON);
+    assert_eq!(one.next_down().get(), 1.0 - f64::EPSILON / 2.0);
+    assert_eq!((-one).next_down(), -one - Float64::EPSILON);
+    assert_eq!((-one).next_up().get(), -1.0 + f64::EPSILON / 2.0);
+}
+
+#[test]
+fn test_ulp() {
+    assert_eq!(Float32::new(1.0).unwrap().ulp(), Float32::EPSILON);
+    assert_eq!(Float32::new(-1.0).unwrap().ulp(), Float32::EPSILON);
+    assert_eq!(
+        Float32::new(3.0).unwrap().ulp(),
+        Float32::EPSILON * Float32::new(2.0).unwrap()
+    );
+    assert_eq!(Float32::ZERO.ulp(), Float32::MIN_POSITIVE);
+    assert_eq!(Float32::MIN_POSITIVE.ulp(), Float32::MIN_POSITIVE);
+    assert_eq!(Float32::MAX.ulp().get(), 2.0f32.powi(104));
+    assert_eq!(Float32::MIN.ulp().get(), 2.0f32.powi(104));
+
+    assert_eq!(Float64::new(1.0).unwrap().ulp(), Float64::EPSILON);
+    assert_eq!(Float64::new(-1.0).unwrap().ulp(), Float64::EPSILON);
+    assert_eq!(
+        Float64::new(3.0).unwrap().ulp(),
+        Float64::EPSILON * Float64::new(2.0).unwrap()
+    );
+    assert_eq!(Float64::ZERO.ulp(), Float64::MIN_POSITIVE);
+    assert_eq!(Float64::MIN_POSITIVE.ulp(), Float64::MIN_POSITIVE);
+    assert_eq!(Float64::MAX.ulp().get(), 2.0f64.powi(971));
+    assert_eq!(Float64::MIN.ulp().get(), 2.0f64.powi(971));
+}
+
+#[test]
+fn test_const_get() {
+    const MAX32: f32 = Float32::MAX.get();
+    const PI32: f32 = Float32::PI.get();
+    const MAX64: f64 = Float64::MAX.get();
+    const PI64: f64 = Float64::PI.get();
+    assert_eq!(MAX32, f32::MAX);
+    assert_eq!(PI32, std::f32::consts::PI);
+    assert_eq!(MAX64, f64::MAX);
+    assert_eq!(PI64, std::f64::consts::PI);
+}
+
+#[test]
+fn test_bits() {
+    for &x in &[
+        Float32::ZERO,
+        Float32::new(3.5).unwrap(),
+        Float32::MIN,
+        Float32::MAX,
+        Float32::MIN_POSITIVE,
+        Float32::MAX_NEGATIVE,
+    ] {
+        assert_eq!(x.to_bits(), x.get().to_bits());
+        assert_eq!(Float32::from_bits(x.to_bits()), x);
+    }
+    assert_eq!(Float32::ZERO.to_bits(), 0);
+    assert_eq!(Float32::from_bits(f32::NAN.to_bits()), Float32::ZERO);
+    assert_eq!(Float32::from_bits(0xffff_ffff), Float32::ZERO);
+    assert_eq!(Float32::from_bits(f32::INFINITY.to_bits()), Float32::MAX);
+    assert_eq!(
+        Float32::from_bits(f32::NEG_INFINITY.to_bits()),
+        Float32::MIN
+    );
+    assert_eq!(Float32::from_bits((-0.0f32).to_bits()).to_bits(), 0);
+    assert_eq!(Float32::from_bits(1), Float32::MIN_POSITIVE);
+
+    for &x in &[
+        Float64::ZERO,
+        Float64::new(3.5).unwrap(),
+        Float64::MIN,
+        Float64::MAX,
+        Float64::MIN_POSITIVE,
+        Float64::MAX_NEGATIVE,
+    ] {
+        assert_eq!(x.to_bits(), x.get().to_bits());
+        assert_eq!(Float64::from_bits(x.to_bits()), x);
+    }
+    assert_eq!(Float64::ZERO.to_bits(), 0);
+    assert_eq!(Float64::from_bits(f64::NAN.to_bits()), Float64::ZERO);
+    assert_eq!(Float64::from_bits(0xffff_ffff_ffff_ffff), Float64::ZERO);
+    assert_eq!(Float64::from_bits(f64::INFINITY.to_bits()), Float64::MAX);
+    assert_eq!(
+        Float64::from_bits(f64::NEG_INFINITY.to_bits()),
+        Float64::MIN
+    );
+    assert_eq!(Float64::from_bits((-0.0f64).to_bits()).to_bits(), 0);
+    assert_eq!(Float64::from_bits(1), Float64::MIN_POSITIVE);
+}
+
+#[test]
+fn test_lerp() {
+    let a = Float32::new(1e-10).unwrap();
+    let b = Float32::new(-3e10).unwrap();
+    assert_eq!(Float32::lerp(a, b, Float32::ZERO), a);
+    assert_eq!(Float32::lerp(a, b, Float32::new(1.0).unwrap()), b);
+    let a = Float32::new(2.0).unwrap();
+    let b = Float32::new(4.0).unwrap();
+    assert_eq!(
+        Float32::lerp(a, b, Float32::new(0.5).unwrap()),
+        Float32::new(3.0).unwrap()
+    );
+    assert_eq!(
+        Float32::lerp(a, b, Float32::new(2.0).unwrap()),
+        Float32::new(6.0).unwrap()
+    );
+    assert_eq!(Float32::lerp(a, b, Float32::MAX), Float32::MAX);
+
+    let a = Float64::new(1e-100).unwrap();
+    let b = Float64::new(-3e100).unwrap();
+    assert_eq!(Float64::lerp(a, b, Float64::ZERO), a);
+    assert_eq!(Float64::lerp(a, b, Float64::new(1.0).unwrap()), b);
+    let a = Float64::new(2.0).unwrap();
+    let b = Float64::new(4.0).unwrap();
+    assert_eq!(
+        Float64::lerp(a, b, Float64::new(0.5).unwrap()),
+        Float64::new(3.0).unwrap()
+    );
+    assert_eq!(
+        Float64::lerp(a, b, Float64::new(2.0).unwrap()),
+        Float64::new(6.0).unwrap()
+    );
+    assert_eq!(Float64::lerp(a, b, Float64::MAX), Float64::MAX);
+
+    // Wide ranges where b - a overflows.
+    assert_eq!(
+        Float32::lerp(Float32::MIN, Float32::MAX, Float32::new(0.5).unwrap()),
+        Float32::ZERO
+    );
+    assert_eq!(
+        Float32::lerp(Float32::MIN, Float32::MAX, Float32::ZERO),
+        Float32::MIN
+    );
+    assert_eq!(
+        Float32::lerp(Float32::MIN, Float32::MAX, Float32::ONE),
+        Float32::MAX
+    );
+    let x = Float32::lerp(Float32::MAX, Float32::MIN, Float32::new(0.25).unwrap());
+    assert!((x.get() / (f32::MAX / 2.0) - 1.0).abs() < 1e-6);
+    assert_eq!(
+        Float64::lerp(Float64::MIN, Float64::MAX, Float64::new(0.5).unwrap()),
+        Float64::ZERO
+    );
+    assert_eq!(
+        Float64::lerp(Float64::MIN, Float64::MAX, Float64::ZERO),
+        Float64::MIN
+    );
+    assert_eq!(
+        Float64::lerp(Float64::MIN, Float64::MAX, Float64::ONE),
+        Float64::MAX
+    );
+    let x = Float64::lerp(Float64::MAX, Float64::MIN, Float64::new(0.25).unwrap());
+    assert!((x.get() / (f64::MAX / 2.0) - 1.0).abs() < 1e-15);
+}
+
+#[test]
+fn test_midpoint() {
+    let two = Float32::new(2.0).unwrap();
+    let four = Float32::new(4.0).unwrap();
+    assert_eq!(two.midpoint(four), Float32::new(3.0).unwrap());
+    assert_eq!((-two).midpoint(four), Float32::new(1.0).unwrap());
+    assert_eq!(Float32::MAX.midpoint(Float32::MAX), Float32::MAX);
+    assert_eq!(Float32::MIN.midpoint(Float32::MIN), Float32::MIN);
+    assert_eq!(Float32::MAX.midpoint(Float32::MIN), Float32::ZERO);
+    assert_eq!(Float32::MAX.midpoint(Float32::ZERO), Float32::MAX / two);
+    assert_eq!(
+        Float32::MIN_POSITIVE.midpoint(Float32::MIN_POSITIVE),
+        Float32::MIN_POSITIVE
+    );
+    assert_eq!(
+        Float32::MIN_POSITIVE
+            .next_up()
+            .midpoint(Float32::MAX_NEGATIVE),
+        Float32::MIN_POSITIVE
+    );
+
+    let two = Float64::new(2.0).unwrap();
+    let four = Float64::new(4.0).unwrap();
+    assert_eq!(two.midpoint(four), Float64::new(3.0).unwrap());
+    assert_eq!((-two).midpoint(four), Float64::new(1.0).unwrap());
+    assert_eq!(Float64::MAX.midpoint(Float64::MAX), Float64::MAX);
+    assert_eq!(Float64::MIN.midpoint(Float64::MIN), Float64::MIN);
+    assert_eq!(Float64::MAX.midpoint(Float64::MIN), Float64::ZERO);
+    assert_eq!(Float64::MAX.midpoint(Float64::ZERO), Float64::MAX / two);
+    assert_eq!(
+        Float64::MIN_POSITIVE.midpoint(Float64::MIN_POSITIVE),
+        Float64::MIN_POSITIVE
+    );
+    assert_eq!(
+        Float64::MIN_POSITIVE
+            .next_up()
+            .midpoint(Float64::MAX_NEGATIVE),
+        Float64::MIN_POSITIVE
+    );
+}
+
+#[test]
+fn test_abs_diff() {
+    let a = Float32::new(2.0).unwrap();
+    let b = Float32::new(-3.0).unwrap();
+    assert_eq!(a.abs_diff(b), Float32::new(5.0).unwrap());
+    assert_eq!(b.abs_diff(a), Float32::new(5.0).unwrap());
+    assert_eq!(a.abs_diff(a), Float32::ZERO);
+    assert_eq!(Float32::MAX.abs_diff(Float32::MIN), Float32::MAX);
+    assert_eq!(Float32::MIN.abs_diff(Float32::MAX), Float32::MAX);
+
+    let a = Float64::new(2.0).unwrap();
+    let b = Float64::new(-3.0).unwrap();
+    assert_eq!(a.abs_diff(b), Float64::new(5.0).unwrap());
+    assert_eq!(b.abs_diff(a), Float64::new(5.0).unwrap());
+    assert_eq!(a.abs_diff(a), Float64::ZERO);
+    assert_eq!(Float64::MAX.abs_diff(Float64::MIN), Float64::MAX);
+    assert_eq!(Float64::MIN.abs_diff(Float64::MAX), Float64::MAX);
+}
+
+#[test]
+fn test_sign_predicates() {
+    for &(x, pos, neg, zero) in &[
+        (Float32::ZERO, false, false, true),
+        (Float32::MIN_POSITIVE, true, false, false),
+        (Float32::MAX_NEGATIVE, false, true, false),
+        (Float32::new(3.0).unwrap(), true, false, false),
+        (Float32::new(-3.0).unwrap(), false, true, false),
+    ] {
+        assert_eq!(x.is_positive(), pos);
+        assert_eq!(x.is_negative(), neg);
+        assert_eq!(x.is_zero(), zero);
+    }
+    assert!(Float32::new(-0.0).unwrap().is_zero());
+    assert!(!Float32::new(-0.0).unwrap().is_negative());
+
+    for &(x, pos, neg, zero) in &[
+        (Float64::ZERO, false, false, true),
+        (Float64::MIN_POSITIVE, true, false, false),
+        (Float64::MAX_NEGATIVE, false, true, false),
+        (Float64::new(3.0).unwrap(), true, false, false),
+        (Float64::new(-3.0).unwrap(), false, true, false),
+    ] {
+        assert_eq!(x.is_positive(), pos);
+        assert_eq!(x.is_negative(), neg);
+        assert_eq!(x.is_zero(), zero);
+    }
+    assert!(Float64::new(-0.0).unwrap().is_zero());
+    assert!(!Float64::new(-0.0).unwrap().is_negative());
+}
+
+#[test]
+fn test_cmp_primitive() {
+    let x = Float32::new(2.0).unwrap();
+    assert!(x == 2.0);
+    assert!(2.0 == x);
+    assert!(x != 3.0);
+    assert!(x < 3.0);
+    assert!(3.0 > x);
+    assert!(x > -1.0);
+    let nan = f32::NAN;
+    assert!(x != nan);
+    assert!(nan != x);
+    assert_eq!(x.partial_cmp(&nan), None);
+    assert_eq!(nan.partial_cmp(&x), None);
+    assert!(Float32::MAX < f32::INFINITY);
+    assert!(Float32::MIN > f32::NEG_INFINITY);
+    assert!(Float32::ZERO == -0.0);
+
+    let x = Float64::new(2.0).unwrap();
+    assert!(x == 2.0);
+    assert!(2.0 == x);
+    assert!(x != 3.0);
+    assert!(x < 3.0);
+    assert!(3.0 > x);
+    assert!(x > -1.0);
+    let nan = f64::NAN;
+    assert!(x != nan);
+    assert!(nan != x);
+    assert_eq!(x.partial_cmp(&nan), None);
+    assert_eq!(nan.partial_cmp(&x), None);
+    assert!(Float64::MAX < f64::INFINITY);
+    assert!(Float64::MIN > f64::NEG_INFINITY);
+    assert!(Float64::ZERO == -0.0);
+}
+
+#[test]
+fn test_compensated_sum() {
+    assert_eq!(Sum32::new().finish(), Float32::ZERO);
+    let one = Float32::new(1.0).unwrap();
+    let tiny = Float32::new(1e-8).unwrap();
+    let mut sum = Sum32::new();
+    let mut naive = one;
+    sum.add(one);
+    for _ in 0..10000 {
+        sum.add(tiny);
+        naive += tiny;
+    }
+    assert_eq!(naive, one);
+    assert!((sum.finish().get() - 1.0001).abs() < 1e-6);
+    let mut sum = Sum32::new();
+    sum.add(Float32::MAX);
+    sum.add(Float32::MAX);
+    sum.add(Float32::MIN);
+    assert_eq!(sum.finish(), Float32::MAX);
+
+    assert_eq!(Sum64::new().finish(), Float64::ZERO);
+    let one = Float64::new(1.0).unwrap();
+    let tiny = Float64::new(1e-17).unwrap();
+    let mut sum = Sum64::new();
+    let mut naive = one;
+    sum.add(one);
+    for _ in 0..10000 {
+        sum.add(tiny);
+        naive += tiny;
+    }
+    assert_eq!(naive, one);
+    assert!((sum.finish().get() - (1.0 + 1e-13)).abs() < 1e-15);
+    let mut sum = Sum64::new();
+    sum.add(Float64::MIN);
+    sum.add(Float64::MIN);
+    sum.add(Float64::MAX);
+    assert_eq!(sum.finish(), Float64::MIN);
+    // Overflow of a partial sum is permanent.
+    let mut sum = Sum64::new();
+    sum.add(Float64::MAX);
+    sum.add(Float64::MAX);
+    sum.add(Float64::MIN);
+    sum.add(Float64::MIN);
+    assert_eq!(sum.finish(), Float64::MAX);
+    let mut sum = Sum32::new();
+    sum.add(Float32::MIN);
+    sum.add(Float32::MIN);
+    sum.add(Float32::MAX);
+    sum.add(Float32::MAX);
+    assert_eq!(sum.finish(), Float32::MIN);
+}
+
+#[test]
+fn test_dot() {
+    let one = Float32::ONE;
+    let three = Float32::new(3.0).unwrap();
+    assert_eq!(Float32::dot(&[], &[]), Float32::ZERO);
+    assert_eq!(
+        Float32::dot(&[Float32::ZERO, Float32::ZERO], &[three, three]),
+        Float32::ZERO
+    );
+    assert_eq!(
+        Float32::dot(&[one, Float32::ZERO], &[Float32::ZERO, one]),
+        Float32::ZERO
+    );
+    assert_eq!(
+        Float32::dot(
+            &[one, Float32::new(2.0).unwrap(), three],
+            &[
+                Float32::new(4.0).unwrap(),
+                Float32::new(-5.0).unwrap(),
+                Float32::new(6.0).unwrap()
+            ]
+        ),
+        Float32::new(12.0).unwrap()
+    );
+    let v = [Float32::MAX, Float32::MAX];
+    assert_eq!(Float32::dot(&v, &v), Float32::MAX);
+
+    let one = Float64::ONE;
+    let three = Float64::new(3.0).unwrap();
+    assert_eq!(dot(&[], &[]), Float64::ZERO);
+    assert_eq!(
+        dot(&[Float64::ZERO, Float64::ZERO], &[three, three]),
+        Float64::ZERO
+    );
+    assert_eq!(
+        dot(&[one, Float64::ZERO], &[Float64::ZERO, one]),
+        Float64::ZERO
+    );
+    assert_eq!(
+        dot(
+            &[one, Float64::new(2.0).unwrap(), three],
+            &[
+                Float64::new(4.0).unwrap(),
+                Float64::new(-5.0).unwrap(),
+                Float64::new(6.0).unwrap()
+            ]
+        ),
+        Float64::new(12.0).unwrap()
+    );
+    let v = [Float64::MAX, Float64::MAX];
+    assert_eq!(dot(&v, &v), Float64::MAX);
+    assert_eq!(Float64::dot(&v, &v), Float64::MAX);
+}
+
+#[test]
+#[should_panic]
+fn test_dot_different_lengths() {
+    dot(&[Float64::ZERO], &[]);
+}
+
+#[test]
+fn test_parse_error() {
+    let err = "foo".parse::<Float64>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "foo".parse::<f64>().unwrap_err().to_string()
+    );
+    assert!(err.source().is_some());
+    let err = "NaN".parse::<Float64>().unwrap_err();
+    assert_eq!(err.to_string(), "NaN is not a finite float");
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn test_parse_hex() {
+    // Normal.
+    assert_eq!("0x1.8p3".parse::<Float32>().unwrap().get(), 12.0);
+    assert_eq!("0x1.8p3".parse::<Float64>().unwrap().get(), 12.0);
+    assert_eq!("-0X1.8P3".parse::<Float32>().unwrap().get(), -12.0);
+    assert_eq!("-0X1.8P3".parse::<Float64>().unwrap().get(), -12.0);
+    assert_eq!("+0xff".parse::<Float32>().unwrap().get(), 255.0);
+    assert_eq!("+0xff".parse::<Float64>().unwrap().get(), 255.0);
+    assert_eq!("0x.8".parse::<Float32>().unwrap().get(), 0.5);
+    assert_eq!("0x.8".parse::<Float64>().unwrap().get(), 0.5);
+    assert_eq!("0x10p-6".parse::<Float32>().unwrap().get(), 0.25);
+    assert_eq!("0x10p-6".parse::<Float64>().unwrap().get(), 0.25);
+    assert_eq!("0x1.fffffep127".parse::<Float32>().unwrap(), Float32::MAX);
+    assert_eq!(
+        "0x1.fffffffffffffp1023".parse::<Float64>().unwrap(),
+        Float64::MAX
+    );
+    assert_eq!(
+        "0x1p-126".parse::<Float32>().unwrap(),
+        Float32::MIN_POSITIVE
+    );
+    assert_eq!(
+        "0x1p-1022".parse::<Float64>().unwrap(),
+        Float64::MIN_POSITIVE
+    );
+
+    // Rounding.
+    assert_eq!("0x1.000001p0".parse::<Float32>().unwrap().get(), 1.0);
+    assert_eq!(
+        "0x1.0000010000000000000000001p0"
+            .parse::<Float32>()
+            .unwrap()
+            .get(),
+        1.0 + f32::EPSILON
+    );
+    assert_eq!(
+        "0x1.00000000000008p0".parse::<Float64>().unwrap().get(),
+        1.0
+    );
+    assert_eq!(
+        "0x1.000000000000080000000000001p0"
+            .parse::<Float64>()
+            .unwrap()
+            .get(),
+        1.0 + f64::EPSILON
+    );
+
+    // Zero.
+    assert_eq!("0x0p0".parse::<Float32>().unwrap(), Float32::ZERO);
+    assert_eq!("0x0p0".parse::<Float64>().unwrap(), Float64::ZERO);
+    assert_eq!("-0x0.000p-10000".parse::<Float32>().unwrap(), Float32::ZERO);
+    assert_eq!("-0x0.000p-10000".parse::<Float64>().unwrap(), Float64::ZERO);
+    assert!("-0x0.0"
+        .parse::<Float32>()
+        .unwrap()
+        .get()
+        .is_sign_positive());
+    assert!("-0x0.0"
+        .parse::<Float64>()
+        .unwrap()
+        .get()
+        .is_sign_positive());
+
+    // Overflow.
+    assert_eq!("0x1p128".parse::<Float32>().unwrap(), Float32::MAX);
+    assert_eq!("0x1p1024".parse::<Float64>().unwrap(), Float64::MAX);
+    assert_eq!("-0x1p99999999999".parse::<Float32>().unwrap(), Float32::MIN);
+    assert_eq!("-0x1p99999999999".parse::<Float64>().unwrap(), Float64::MIN);
+
+    // Underflow.
+    assert_eq!(
+        "0x1p-140".parse::<Float32>().unwrap(),
+        Float32::MIN_POSITIVE
+    );
+    assert_eq!(
+        "0x0.00ap-10000".parse::<Float32>().unwrap(),
+        Float32::MIN_POSITIVE
+    );
+    assert_eq!(
+        "0x0.00ap-10000".parse::<Float64>().unwrap(),
+        Float64::MIN_POSITIVE
+    );
+    assert_eq!(
+        "-0x0.00Fp-99999999999".parse::<Float32>().unwrap(),
+        Float32::MAX_NEGATIVE
+    );
+    assert_eq!(
+        "-0x0.00Fp-99999999999".parse::<Float64>().unwrap(),
+        Float64::MAX_NEGATIVE
+    );
+
+    // Invalid.
+    for s in &[
+        "0x", "-0x", "0x.", "0xp1", "0x1p", "0x1p+", "0x1g", "0x1.2.3", "0x1p1.5",
+    ] {
+        assert!(s.parse::<Float32>().is_err());
+        assert!(s.parse::<Float64>().is_err());
+    }
+}
+
+#[test]
+fn test_ordered_bits() {
+    let mut a = vec![
+        Float32::MAX,
+        Float32::MIN_POSITIVE,
+        Float32::new(-2.5).unwrap(),
+        Float32::ZERO,
+        Float32::MIN,
+        Float32::new(1.0).unwrap(),
+        Float32::MAX_NEGATIVE,
+        Float32::new(-1.0).unwrap(),
+    ];
+    let mut b = a.clone();
+    a.sort();
+    b.sort_by_key(|x| x.to_ordered_bits());
+    assert_eq!(a, b);
+    for &x in &a {
+        assert_eq!(Float32::from_ordered_bits(x.to_ordered_bits()), x);
+    }
+    assert_eq!(Float32::ZERO.to_ordered_bits(), 0x8000_0000);
+    assert_eq!(Float32::from_ordered_bits(0x7fff_ffff), Float32::ZERO);
+    assert_eq!(Float32::from_ordered_bits(u32::MAX), Float32::ZERO);
+    assert_eq!(Float32::from_ordered_bits(0), Float32::ZERO);
+    assert_eq!(
+        Float32::from_ordered_bits(0x8000_0001),
+        Float32::MIN_POSITIVE
+    );
+
+    let mut a = vec![
+        Float64::MAX,
+        Float64::MIN_POSITIVE,
+        Float64::new(-2.5).unwrap(),
+        Float64::ZERO,
+        Float64::MIN,
+        Float64::new(1.0).unwrap(),
+        Float64::MAX_NEGATIVE,
+        Float64::new(-1.0).unwrap(),
+    ];
+    let mut b = a.clone();
+    a.sort();
+    b.sort_by_key(|x| x.to_ordered_bits());
+    assert_eq!(a, b);
+    for &x in &a {
+        assert_eq!(Float64::from_ordered_bits(x.to_ordered_bits()), x);
+    }
+    assert_eq!(Float64::ZERO.to_ordered_bits(), 0x8000_0000_0000_0000);
+    assert_eq!(
+        Float64::from_ordered_bits(0x7fff_ffff_ffff_ffff),
+        Float64::ZERO
+    );
+    assert_eq!(Float64::from_ordered_bits(u64::MAX), Float64::ZERO);
+    assert_eq!(Float64::from_ordered_bits(0), Float64::ZERO);
+    assert_eq!(
+        Float64::from_ordered_bits(0x8000_0000_0000_0001),
+        Float64::MIN_POSITIVE
+    );
+}
+
+#[test]
+fn test_scalb() {
+    let x = Float32::new(1.5).unwrap();
+    assert_eq!(x.scalb(0), x);
+    assert_eq!(x.scalb(3).get(), 12.0);
+    assert_eq!(x.scalb(-2).get(), 0.375);
+    assert_eq!((-x).scalb(100).get(), -1.5 * 2f32.powi(100));
+    assert_eq!(
+        Float32::MIN_POSITIVE.scalb(200).scalb(-200),
+        Float32::MIN_POSITIVE
+    );
+    assert_eq!(x.scalb(128), Float32::MAX);
+    assert_eq!((-x).scalb(i32::MAX), Float32::MIN);
+    assert_eq!(x.scalb(-127), Float32::MIN_POSITIVE);
+    assert_eq!((-x).scalb(i32::MIN), Float32::MAX_NEGATIVE);
+    assert_eq!(Float32::ZERO.scalb(i32::MAX), Float32::ZERO);
+    assert_eq!(Float32::ZERO.scalb(i32::MIN), Float32::ZERO);
+
+    let x = Float64::new(1.5).unwrap();
+    assert_eq!(x.scalb(0), x);
+    assert_eq!(x.scalb(3).get(), 12.0);
+    assert_eq!(x.scalb(-2).get(), 0.375);
+    assert_eq!((-x).scalb(1000).get(), -1.5 * 2f64.powi(1000));
+    assert_eq!(
+        Float64::MIN_POSITIVE.scalb(2000).scalb(-2000),
+        Float64::MIN_POSITIVE
+    );
+    assert_eq!(x.scalb(1024), Float64::MAX);
+    assert_eq!((-x).scalb(i32::MAX), Float64::MIN);
+    assert_eq!(x.scalb(-1023), Float64::MIN_POSITIVE);
+    assert_eq!((-x).scalb(i32::MIN), Float64::MAX_NEGATIVE);
+    assert_eq!(Float64::ZERO.scalb(i32::MAX), Float64::ZERO);
+    assert_eq!(Float64::ZERO.scalb(i32::MIN), Float64::ZERO);
+}
+
+#[test]
+fn test_frexp() {
+    assert_eq!(Float32::ZERO.frexp(), (Float32::ZERO, 0));
+    assert_eq!(
+        Float32::new(12.0).unwrap().frexp(),
+        (Float32::new(0.75).unwrap(), 4)
+    );
+    assert_eq!(
+        Float32::new(-0.375).unwrap().frexp(),
+        (Float32::new(-0.75).unwrap(), -1)
+    );
+    assert_eq!(Float32::new(1.0).unwrap().frexp().1, 1);
+    assert_eq!(Float32::MIN_POSITIVE.frexp().1, -125);
+    for &x in &[
+        Float32::ZERO,
+        Float32::MIN_POSITIVE,
+        Float32::MIN_POSITIVE.next_up(),
+        Float32::MAX_NEGATIVE.next_down(),
+        Float32::PI,
+        Float32::MAX,
+        Float32::MIN,
+        Float32::MAX.next_down(),
+    ] {
+        let (m, e) = x.frexp();
+        assert!(m == Float32::ZERO || (m.abs().get() >= 0.5 && m.abs().get() < 1.0));
+        assert_eq!(m.is_negative(), x.is_negative());
+        assert_eq!(m.scalb(e), x);
+    }
+
+    assert_eq!(Float64::ZERO.frexp(), (Float64::ZERO, 0));
+    assert_eq!(
+        Float64::new(12.0).unwrap().frexp(),
+        (Float64::new(0.75).unwrap(), 4)
+    );
+    assert_eq!(
+        Float64::new(-0.375).unwrap().frexp(),
+        (Float64::new(-0.75).unwrap(), -1)
+    );
+    assert_eq!(Float64::new(1.0).unwrap().frexp().1, 1);
+    assert_eq!(Float64::MIN_POSITIVE.frexp().1, -1021);
+    for &x in &[
+        Float64::ZERO,
+        Float64::MIN_POSITIVE,
+        Float64::MIN_POSITIVE.next_up(),
+        Float64::MAX_NEGATIVE.next_down(),
+        Float64::PI,
+        Float64::MAX,
+        Float64::MIN,
+        Float64::MAX.next_down(),
+    ] {
+        let (m, e) = x.frexp();
+        assert!(m == Float64::ZERO || (m.abs().get() >= 0.5 && m.abs().get() < 1.0));
+        assert_eq!(m.is_negative(), x.is_negative());
+        assert_eq!(m.scalb(e), x);
+    }
+}
+
+#[test]
+fn test_finite_float_trait() {
+    fn normalize<F: FiniteFloat>(xs: &mut [F]) {
+        let mut norm = F::ZERO;
+        for &x in xs.iter() {
+            norm = norm.max(x.abs());
+        }
+        if !norm.is_zero() {
+            for x in xs.iter_mut() {
+                *x /= norm;
+            }
+        }
+    }
+
+    fn parse_all<F: FiniteFloat>(xs: &[&str]) -> Vec<F> {
+        xs.iter().map(|x| x.parse().unwrap()).collect()
+    }
+
+    let mut a: Vec<Float32> = parse_all(&["1", "-4", "2"]);
+    normalize(&mut a);
+    assert_eq!(a, parse_all::<Float32>(&["0.25", "-1", "0.5"]));
+    let mut z = [Float32::ZERO; 2];
+    normalize(&mut z);
+    assert_eq!(z, [Float32::ZERO; 2]);
+    assert_eq!(<Float32 as FiniteFloat>::MAX, Float32::MAX);
+    assert_eq!(<Float32 as FiniteFloat>::new(f32::NAN), None);
+    assert_eq!(FiniteFloat::get(Float32::MIN), f32::MIN);
+    assert_eq!(
+        FiniteFloat::signum(Float32::MIN),
+        -Float32::new(1.0).unwrap()
+    );
+
+    let mut a: Vec<Float64> = parse_all(&["1", "-4", "2"]);
+    normalize(&mut a);
+    assert_eq!(a, parse_all::<Float64>(&["0.25", "-1", "0.5"]));
+    let mut z = [Float64::ZERO; 2];
+    normalize(&mut z);
+    assert_eq!(z, [Float64::ZERO; 2]);
+    assert_eq!(<Float64 as FiniteFloat>::MAX, Float64::MAX);
+    assert_eq!(<Float64 as FiniteFloat>::new(f64::NAN), None);
+    assert_eq!(FiniteFloat::get(Float64::MIN), f64::MIN);
+    assert_eq!(
+        FiniteFloat::signum(Float64::MIN),
+        -Float64::new(1.0).unwrap()
+    );
+}
+
+#[test]
+fn test_primitive_ops() {
+    let x = Float32::new(3.0).unwrap();
+    assert_eq!((x * 2.0).get(), 6.0);
+    assert_eq!((2.0 * x).get(), 6.0);
+    assert_eq!((x + 0.5).get(), 3.5);
+    assert_eq!((0.5 + x).get(), 3.5);
+    assert_eq!((x - 1.0).get(), 2.0);
+    assert_eq!((1.0 - x).get(), -2.0);
+    assert_eq!((x / 2.0).get(), 1.5);
+    assert_eq!((6.0 / x).get(), 2.0);
+    assert_eq!(x * f32::INFINITY, Float32::MAX);
+    assert_eq!(x / 0.0, Float32::MAX);
+    assert_eq!(Float32::MIN_POSITIVE * 0.5, Float32::MIN_POSITIVE);
+    assert_eq!(-Float32::MIN_POSITIVE * 0.5, Float32::MAX_NEGATIVE);
+    // Subnormal and infinite operands are used as is.
+    assert_eq!(Float32::new(1.0).unwrap() / 1e-40, Float32::MAX);
+    assert_eq!((Float32::new(1e30).unwrap() * 1e-40).get(), 1e30f32 * 1e-40);
+    assert_eq!((1e30f32 * 1e-40 * Float32::ONE).get(), 1e30f32 * 1e-40);
+    assert_eq!(1e-40 * Float32::ONE, Float32::MIN_POSITIVE);
+    assert_eq!(-1e-40 - Float32::ZERO, Float32::MAX_NEGATIVE);
+    assert_eq!(f32::NEG_INFINITY + x, Float32::MIN);
+    assert_eq!(Float32::ZERO * f32::INFINITY, Float32::ZERO);
+    assert_eq!(x / -0.0, Float32::MAX);
+    assert_eq!(0.0 / Float32::ZERO, Float32::ZERO);
+    let mut y = x;
+    y += 1.0;
+    y -= 2.0;
+    y *= 3.0;
+    y /= 4.0;
+    assert_eq!(y.get(), 1.5);
+
+    let x = Float64::new(3.0).unwrap();
+    assert_eq!((x * 2.0).get(), 6.0);
+    assert_eq!((2.0 * x).get(), 6.0);
+    assert_eq!((x + 0.5).get(), 3.5);
+    assert_eq!((0.5 + x).get(), 3.5);
+    assert_eq!((x - 1.0).get(), 2.0);
+    assert_eq!((1.0 - x).get(), -2.0);
+    assert_eq!((x / 2.0).get(), 1.5);
+    assert_eq!((6.0 / x).get(), 2.0);
+    assert_eq!(x * f64::INFINITY, Float64::MAX);
+    assert_eq!(x / 0.0, Float64::MAX);
+    assert_eq!(Float64::MIN_POSITIVE * 0.5, Float64::MIN_POSITIVE);
+    assert_eq!(-Float64::MIN_POSITIVE * 0.5, Float64::MAX_NEGATIVE);
+    // Subnormal and infinite operands are used as is.
+    assert_eq!(Float64::new(1e-10).unwrap() / 1e-320, Float64::MAX);
+    assert_eq!(
+        (Float64::new(1e300).unwrap() * 1e-320).get(),
+        1e300f64 * 1e-320
+    );
+    assert_eq!((1e300f64 * 1e-320 * Float64::ONE).get(), 1e300f64 * 1e-320);
+    assert_eq!(1e-320 * Float64::ONE, Float64::MIN_POSITIVE);
+    assert_eq!(-1e-320 - Float64::ZERO, Float64::MAX_NEGATIVE);
+    assert_eq!(f64::NEG_INFINITY + x, Float64::MIN);
+    assert_eq!(Float64::ZERO * f64::INFINITY, Float64::ZERO);
+    assert_eq!(x / -0.0, Float64::MAX);
+    assert_eq!(0.0 / Float64::ZERO, Float64::ZERO);
+    let mut y = x;
+    y += 1.0;
+    y -= 2.0;
+    y *= 3.0;
+    y /= 4.0;
+    assert_eq!(y.get(), 1.5);
+}
+
+#[test]
+#[should_panic(expected = "NaN operand")]
+fn test_primitive_op_nan_32() {
+    let _ = Float32::ZERO * f32::NAN;
+}
+
+#[test]
+#[should_panic(expected = "NaN operand")]
+fn test_primitive_op_nan_64() {
+    let _ = f64::NAN + Float64::ZERO;
+}
+
+#[test]
+fn test_round_ties_even() {
+    for &(x, ties_even, ties_away) in &[
+        (0.5, 0.0, 1.0),
+        (1.5, 2.0, 2.0),
+        (2.5, 2.0, 3.0),
+        (-0.5, 0.0, -1.0),
+        (-1.5, -2.0, -2.0),
+        (-2.5, -2.0, -3.0),
+        (2.4, 2.0, 2.0),
+        (2.6, 3.0, 3.0),
+        (8388609.0, 8388609.0, 8388609.0),
+    ] {
+        assert_eq!(
+            Float32::new(x).unwrap().round_ties_even(),
+            Float32::new(ties_even).unwrap()
+        );
+        assert_eq!(
+            Float32::new(x).unwrap().round(),
+            Float32::new(ties_away).unwrap()
+        );
+    }
+    assert!(Float32::new(-0.5)
+        .unwrap()
+        .round_ties_even()
+        .get()
+        .is_sign_positive());
+    assert_eq!(Float32::MAX.round_ties_even(), Float32::MAX);
+    assert_eq!(Float32::MIN.round_ties_even(), Float32::MIN);
+    assert_eq!(Float32::MIN_POSITIVE.round_ties_even(), Float32::ZERO);
+
+    for &(x, ties_even, ties_away) in &[
+        (0.5, 0.0, 1.0),
+        (1.5, 2.0, 2.0),
+        (2.5, 2.0, 3.0),
+        (-0.5, 0.0, -1.0),
+        (-1.5, -2.0, -2.0),
+        (-2.5, -2.0, -3.0),
+        (2.4, 2.0, 2.0),
+        (2.6, 3.0, 3.0),
+        (4503599627370497.0, 4503599627370497.0, 4503599627370497.0),
+    ] {
+        assert_eq!(
+            Float64::new(x).unwrap().round_ties_even(),
+            Float64::new(ties_even).unwrap()
+        );
+        assert_eq!(
+            Float64::new(x).unwrap().round(),
+            Float64::new(ties_away).unwrap()
+        );
+    }
+    assert!(Float64::new(-0.5)
+        .unwrap()
+        .round_ties_even()
+        .get()
+        .is_sign_positive());
+    assert_eq!(Float64::MAX.round_ties_even(), Float64::MAX);
+    assert_eq!(Float64::MIN.round_ties_even(), Float64::MIN);
+    assert_eq!(Float64::MIN_POSITIVE.round_ties_even(), Float64::ZERO);
+}
+
+#[test]
+fn test_magnitude() {
+    assert_eq!(
+        Float32::new(-3.0)
+            .unwrap()
+            .cmp_magnitude(Float32::new(2.0).unwrap()),
+        Ordering::Greater
+    );
+    assert_eq!(
+        Float32::new(2.0)
+            .unwrap()
+            .cmp_magnitude(Float32::new(-3.0).unwrap()),
+        Ordering::Less
+    );
+    assert_eq!(
+        Float32::new(-2.0)
+            .unwrap()
+            .cmp_magnitude(Float32::new(2.0).unwrap()),
+        Ordering::Equal
+    );
+    assert_eq!(
+        Float32::ZERO.cmp_magnitude(Float32::MAX_NEGATIVE),
+        Ordering::Less
+    );
+    assert_eq!(
+        Float32::new(-3.0)
+            .unwrap()
+            .max_by_magnitude(Float32::new(2.0).unwrap()),
+        Float32::new(-3.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(2.0)
+            .unwrap()
+            .max_by_magnitude(Float32::new(-3.0).unwrap()),
+        Float32::new(-3.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-3.0)
+            .unwrap()
+            .min_by_magnitude(Float32::new(2.0).unwrap()),
+        Float32::new(2.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(2.0)
+            .unwrap()
+            .min_by_magnitude(Float32::new(-3.0).unwrap()),
+        Float32::new(2.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-2.0)
+            .unwrap()
+            .max_by_magnitude(Float32::new(2.0).unwrap()),
+        Float32::new(2.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(2.0)
+            .unwrap()
+            .max_by_magnitude(Float32::new(-2.0).unwrap()),
+        Float32::new(2.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-2.0)
+            .unwrap()
+            .min_by_magnitude(Float32::new(2.0).unwrap()),
+        Float32::new(-2.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(2.0)
+            .unwrap()
+            .min_by_magnitude(Float32::new(-2.0).unwrap()),
+        Float32::new(-2.0).unwrap()
+    );
+    assert_eq!(Float32::MIN.max_by_magnitude(Float32::MAX), Float32::MAX);
+
+    assert_eq!(
+        Float64::new(-3.0)
+            .unwrap()
+            .cmp_magnitude(Float64::new(2.0).unwrap()),
+        Ordering::Greater
+    );
+    assert_eq!(
+        Float64::new(2.0)
+            .unwrap()
+            .cmp_magnitude(Float64::new(-3.0).unwrap()),
+        Ordering::Less
+    );
+    assert_eq!(
+        Float64::new(-2.0)
+            .unwrap()
+            .cmp_magnitude(Float64::new(2.0).unwrap()),
+        Ordering::Equal
+    );
+    assert_eq!(
+        Float64::ZERO.cmp_magnitude(Float64::MAX_NEGATIVE),
+        Ordering::Less
+    );
+    assert_eq!(
+        Float64::new(-3.0)
+            .unwrap()
+            .max_by_magnitude(Float64::new(2.0).unwrap()),
+        Float64::new(-3.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(2.0)
+            .unwrap()
+            .max_by_magnitude(Float64::new(-3.0).unwrap()),
+        Float64::new(-3.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-3.0)
+            .unwrap()
+            .min_by_magnitude(Float64::new(2.0).unwrap()),
+        Float64::new(2.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(2.0)
+            .unwrap()
+            .min_by_magnitude(Float64::new(-3.0).unwrap()),
+        Float64::new(2.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-2.0)
+            .unwrap()
+            .max_by_magnitude(Float64::new(2.0).unwrap()),
+        Float64::new(2.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(2.0)
+            .unwrap()
+            .max_by_magnitude(Float64::new(-2.0).unwrap()),
+        Float64::new(2.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-2.0)
+            .unwrap()
+            .min_by_magnitude(Float64::new(2.0).unwrap()),
+        Float64::new(-2.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(2.0)
+            .unwrap()
+            .min_by_magnitude(Float64::new(-2.0).unwrap()),
+        Float64::new(-2.0).unwrap()
+    );
+    assert_eq!(Float64::MIN.max_by_magnitude(Float64::MAX), Float64::MAX);
+}
+
+#[test]
+fn test_iter_to() {
+    let v: Vec<f32> = Float32::new(0.0)
+        .unwrap()
+        .iter_to(Float32::new(1.0).unwrap(), Float32::new(0.25).unwrap())
+        .map(Float32::get)
+        .collect();
+    assert_eq!(v, [0.0, 0.25, 0.5, 0.75, 1.0]);
+    let v: Vec<f32> = Float32::new(0.0)
+        .unwrap()
+        .iter_to(Float32::new(1.1).unwrap(), Float32::new(0.5).unwrap())
+        .map(Float32::get)
+        .collect();
+    assert_eq!(v, [0.0, 0.5, 1.0]);
+    let v: Vec<f32> = Float32::new(1.0)
+        .unwrap()
+        .iter_to(Float32::new(0.0).unwrap(), Float32::new(-0.5).unwrap())
+        .map(Float32::get)
+        .collect();
+    assert_eq!(v, [1.0, 0.5, 0.0]);
+    assert_eq!(
+        Float32::new(1.0)
+            .unwrap()
+            .iter_to(Float32::new(0.0).unwrap(), Float32::new(0.5).unwrap())
+            .count(),
+        0
+    );
+    assert_eq!(
+        Float32::new(0.0)
+            .unwrap()
+            .iter_to(Float32::new(1.0).unwrap(), Float32::new(-0.5).unwrap())
+            .count(),
+        0
+    );
+    // No progress.
+    assert_eq!(
+        Float32::new(0.0)
+            .unwrap()
+            .iter_to(Float32::new(1.0).unwrap(), Float32::ZERO)
+            .count(),
+        1
+    );
+    assert_eq!(
+        Float32::new(1e20)
+            .unwrap()
+            .iter_to(Float32::MAX, Float32::new(1.0).unwrap())
+            .count(),
+        1
+    );
+    let v: Vec<Float32> = Float32::MAX
+        .next_down()
+        .iter_to(Float32::MAX, Float32::MAX)
+        .collect();
+    assert_eq!(v, [Float32::MAX.next_down(), Float32::MAX]);
+
+    let v: Vec<f64> = Float64::new(0.0)
+        .unwrap()
+        .iter_to(Float64::new(1.0).unwrap(), Float64::new(0.25).unwrap())
+        .map(Float64::get)
+        .collect();
+    assert_eq!(v, [0.0, 0.25, 0.5, 0.75, 1.0]);
+    let v: Vec<f64> = Float64::new(0.0)
+        .unwrap()
+        .iter_to(Float64::new(1.1).unwrap(), Float64::new(0.5).unwrap())
+        .map(Float64::get)
+        .collect();
+    assert_eq!(v, [0.0, 0.5, 1.0]);
+    let v: Vec<f64> = Float64::new(1.0)
+        .unwrap()
+        .iter_to(Float64::new(0.0).unwrap(), Float64::new(-0.5).unwrap())
+        .map(Float64::get)
+        .collect();
+    assert_eq!(v, [1.0, 0.5, 0.0]);
+    assert_eq!(
+        Float64::new(1.0)
+            .unwrap()
+            .iter_to(Float64::new(0.0).unwrap(), Float64::new(0.5).unwrap())
+            .count(),
+        0
+    );
+    assert_eq!(
+        Float64::new(0.0)
+            .unwrap()
+            .iter_to(Float64::new(1.0).unwrap(), Float64::new(-0.5).unwrap())
+            .count(),
+        0
+    );
+    // No progress.
+    assert_eq!(
+        Float64::new(0.0)
+            .unwrap()
+            .iter_to(Float64::new(1.0).unwrap(), Float64::ZERO)
+            .count(),
+        1
+    );
+    assert_eq!(
+        Float64::new(1e20)
+            .unwrap()
+            .iter_to(Float64::MAX, Float64::new(1.0).unwrap())
+            .count(),
+        1
+    );
+    let v: Vec<Float64> = Float64::MAX
+        .next_down()
+        .iter_to(Float64::MAX, Float64::MAX)
+        .collect();
+    assert_eq!(v, [Float64::MAX.next_down(), Float64::MAX]);
+}
+
+#[test]
+fn test_mean_variance() {
+    let xs = [
+        Float64::new(2.0).unwrap(),
+        Float64::new(4.0).unwrap(),
+        Float64::new(4.0).unwrap(),
+        Float64::new(4.0).unwrap(),
+        Float64::new(5.0).unwrap(),
+        Float64::new(5.0).unwrap(),
+        Float64::new(7.0).unwrap(),
+        Float64::new(9.0).unwrap(),
+    ];
+    assert_eq!(mean(&xs), Some(Float64::new(5.0).unwrap()));
+    assert_eq!(variance(&xs), Some(Float64::new(4.0).unwrap()));
+    assert_eq!(mean(&[]), None);
+    assert_eq!(variance(&[]), None);
+    assert_eq!(
+        mean(&[Float64::new(-3.5).unwrap()]),
+        Some(Float64::new(-3.5).unwrap())
+    );
+    assert_eq!(
+        variance(&[Float64::new(-3.5).unwrap()]),
+        Some(Float64::ZERO)
+    );
+    assert_eq!(mean(&[Float64::MAX, Float64::MAX]), Some(Float64::MAX));
+    assert_eq!(mean(&[Float64::MIN, Float64::MAX]), Some(Float64::ZERO));
+    assert_eq!(variance(&[Float64::MIN, Float64::MAX]), Some(Float64::MAX));
+    let shifted: Vec<Float64> = xs.iter().map(|&x| x + Float64::new(1e9).unwrap()).collect();
+    assert!((variance(&shifted).unwrap().get() - 4.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_gamma() {
+    assert_eq!(
+        Float32::new(1.0).unwrap().gamma(),
+        Float32::new(1.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(5.0).unwrap().gamma(),
+        Float32::new(24.0).unwrap()
+    );
+    assert!((Float32::new(0.5).unwrap().gamma().get() - std::f32::consts::PI.sqrt()).abs() < 1e-6);
+    assert!(
+        (Float32::new(-0.5).unwrap().gamma().get() + 2.0 * std::f32::consts::PI.sqrt()).abs()
+            < 1e-5
+    );
+    assert!((Float32::new(2.5).unwrap().gamma().get() - 1.329_340_4).abs() < 1e-6);
+    assert_eq!(Float32::ZERO.gamma(), Float32::MAX);
+    assert_eq!(Float32::new(-3.0).unwrap().gamma(), Float32::MAX);
+    assert_eq!(Float32::new(40.0).unwrap().gamma(), Float32::MAX);
+    assert_eq!(Float32::new(40.5).unwrap().gamma(), Float32::MAX);
+    assert_eq!(Float32::MAX.gamma(), Float32::MAX);
+    assert_eq!(Float32::new(-100.5).unwrap().gamma(), Float32::MAX_NEGATIVE);
+    assert_eq!(Float32::new(-101.5).unwrap().gamma(), Float32::MIN_POSITIVE);
+    assert!(Float32::new(1.0).unwrap().ln_gamma().get().abs() < 1e-6);
+    assert!(Float32::new(2.0).unwrap().ln_gamma().get().abs() < 1e-6);
+    assert!((Float32::new(5.0).unwrap().ln_gamma().get() - 24f32.ln()).abs() < 1e-5);
+    assert!((Float32::new(-0.5).unwrap().ln_gamma().get() - 1.265_512_1).abs() < 1e-5);
+    assert_eq!(Float32::ZERO.ln_gamma(), Float32::MAX);
+    assert_eq!(Float32::new(-3.0).unwrap().ln_gamma(), Float32::MAX);
+    assert!(Float32::new(40.0).unwrap().ln_gamma().get() > 100.0);
+    // Next to a pole: gamma(-3 + e) = -(1 + digamma(4) e + O(e^2)) / (6 e).
+    let x = Float32::new(-3.0 + 1.0 / 1048576.0).unwrap();
+    let e = f64::from(x.get()) + 3.0;
+    let expected = -(1.0 + 1.256_117_668_431_8 * e) / (6.0 * e);
+    assert!((f64::from(x.gamma().get()) / expected - 1.0).abs() < 1e-6);
+
+    assert_eq!(
+        Float64::new(1.0).unwrap().gamma(),
+        Float64::new(1.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(5.0).unwrap().gamma(),
+        Float64::new(24.0).unwrap()
+    );
+    assert!((Float64::new(0.5).unwrap().gamma().get() - std::f64::consts::PI.sqrt()).abs() < 1e-14);
+    assert!(
+        (Float64::new(-0.5).unwrap().gamma().get() + 2.0 * std::f64::consts::PI.sqrt()).abs()
+            < 1e-13
+    );
+    assert!((Float64::new(2.5).unwrap().gamma().get() - 1.329_340_388_179_137).abs() < 1e-13);
+    assert_eq!(Float64::ZERO.gamma(), Float64::MAX);
+    assert_eq!(Float64::new(-3.0).unwrap().gamma(), Float64::MAX);
+    assert_eq!(Float64::new(172.0).unwrap().gamma(), Float64::MAX);
+    assert_eq!(Float64::new(200.5).unwrap().gamma(), Float64::MAX);
+    assert_eq!(Float64::MAX.gamma(), Float64::MAX);
+    assert_eq!(Float64::new(-200.5).unwrap().gamma(), Float64::MAX_NEGATIVE);
+    assert_eq!(Float64::new(-201.5).unwrap().gamma(), Float64::MIN_POSITIVE);
+    assert!(Float64::new(1.0).unwrap().ln_gamma().get().abs() < 1e-14);
+    assert!(Float64::new(2.0).unwrap().ln_gamma().get().abs() < 1e-14);
+    assert!((Float64::new(5.0).unwrap().ln_gamma().get() - 24f64.ln()).abs() < 1e-13);
+    assert!((Float64::new(-0.5).unwrap().ln_gamma().get() - 1.265_512_123_484_645_4).abs() < 1e-13);
+    assert_eq!(Float64::ZERO.ln_gamma(), Float64::MAX);
+    assert_eq!(Float64::new(-3.0).unwrap().ln_gamma(), Float64::MAX);
+    assert!(Float64::new(200.0).unwrap().ln_gamma().get() > 800.0);
+    // Next to a pole: gamma(-3 + e) = -(1 + digamma(4) e + O(e^2)) / (6 e).
+    for &x in &[-3.0 + 1e-12, -3.0000001] {
+        let x = Float64::new(x).unwrap();
+        let e = x.get() + 3.0;
+        let expected = -(1.0 + 1.256_117_668_431_8 * e) / (6.0 * e);
+        assert!((x.gamma().get() / expected - 1.0).abs() < 1e-12);
+        assert!((x.ln_gamma().get() - expected.abs().ln()).abs() < 1e-12);
+    }
+    assert!(Float64::MAX.ln_gamma().get() > 1e300);
+}
+
+#[test]
+fn test_erf() {
+    assert_eq!(Float32::ZERO.erf(), Float32::ZERO);
+    assert_eq!(Float32::ZERO.erfc(), Float32::new(1.0).unwrap());
+    assert!((Float32::new(0.5).unwrap().erf().get() - 0.520_499_9).abs() < 1e-6);
+    assert!((Float32::new(-1.0).unwrap().erf().get() + 0.842_700_8).abs() < 1e-6);
+    assert!((Float32::new(3.0).unwrap().erfc().get() - 2.209_05e-5).abs() < 1e-10);
+    assert!((Float32::new(-1.0).unwrap().erfc().get() - 1.842_700_8).abs() < 1e-6);
+    assert_eq!(
+        Float32::new(10.0).unwrap().erf(),
+        Float32::new(1.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-10.0).unwrap().erf(),
+        Float32::new(-1.0).unwrap()
+    );
+    assert_eq!(Float32::MAX.erf(), Float32::new(1.0).unwrap());
+    assert_eq!(Float32::MIN.erf(), Float32::new(-1.0).unwrap());
+    assert_eq!(Float32::MIN.erfc(), Float32::new(2.0).unwrap());
+    assert_eq!(Float32::MAX.erfc(), Float32::MIN_POSITIVE);
+    assert!(Float32::MIN_POSITIVE.erf().is_positive());
+    assert!(Float32::MAX_NEGATIVE.erf().is_negative());
+
+    assert_eq!(Float64::ZERO.erf(), Float64::ZERO);
+    assert_eq!(Float64::ZERO.erfc(), Float64::new(1.0).unwrap());
+    assert!((Float64::new(0.5).unwrap().erf().get() - 0.520_499_877_813_046_5).abs() < 1e-15);
+    assert!((Float64::new(-1.0).unwrap().erf().get() + 0.842_700_792_949_714_9).abs() < 1e-15);
+    assert!(
+        (Float64::new(3.0).unwrap().erfc().get() / 2.209_049_699_858_544e-5 - 1.0).abs() < 1e-14
+    );
+    assert!(
+        (Float64::new(10.0).unwrap().erfc().get() / 2.088_487_583_762_545e-45 - 1.0).abs() < 1e-14
+    );
+    assert!((Float64::new(-1.0).unwrap().erfc().get() - 1.842_700_792_949_715).abs() < 1e-15);
+    assert_eq!(
+        Float64::new(10.0).unwrap().erf(),
+        Float64::new(1.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-10.0).unwrap().erf(),
+        Float64::new(-1.0).unwrap()
+    );
+    assert_eq!(Float64::MAX.erf(), Float64::new(1.0).unwrap());
+    assert_eq!(Float64::MIN.erf(), Float64::new(-1.0).unwrap());
+    assert_eq!(Float64::MIN.erfc(), Float64::new(2.0).unwrap());
+    assert_eq!(Float64::MAX.erfc(), Float64::MIN_POSITIVE);
+    assert!(Float64::MIN_POSITIVE.erf().is_positive());
+    assert!(Float64::MAX_NEGATIVE.erf().is_negative());
+    // Reference values, accurate to the last digit.
+    for &(x, expected) in &[
+        (0.99, 0.161_491_930_444_630_2),
+        (1.0, 0.157_299_207_050_285_13),
+        (1.5, 0.033_894_853_524_689_274),
+        (1.9, 0.007_209_570_764_742_532_5),
+    ] {
+        let res = Float64::new(x).unwrap().erfc().get();
+        assert!((res / expected - 1.0).abs() < 4.0 * f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_clamp01() {
+    assert_eq!(Float32::ONE.get(), 1.0);
+    assert_eq!(Float32::new(-0.5).unwrap().clamp01(), Float32::ZERO);
+    assert_eq!(Float32::MIN.clamp01(), Float32::ZERO);
+    assert_eq!(Float32::MAX_NEGATIVE.clamp01(), Float32::ZERO);
+    assert_eq!(Float32::ZERO.clamp01(), Float32::ZERO);
+    assert_eq!(Float32::MIN_POSITIVE.clamp01(), Float32::MIN_POSITIVE);
+    assert_eq!(
+        Float32::new(0.25).unwrap().clamp01(),
+        Float32::new(0.25).unwrap()
+    );
+    assert_eq!(Float32::ONE.clamp01(), Float32::ONE);
+    assert_eq!(Float32::ONE.next_up().clamp01(), Float32::ONE);
+    assert_eq!(Float32::MAX.clamp01(), Float32::ONE);
+
+    assert_eq!(Float64::ONE.get(), 1.0);
+    assert_eq!(Float64::new(-0.5).unwrap().clamp01(), Float64::ZERO);
+    assert_eq!(Float64::MIN.clamp01(), Float64::ZERO);
+    assert_eq!(Float64::MAX_NEGATIVE.clamp01(), Float64::ZERO);
+    assert_eq!(Float64::ZERO.clamp01(), Float64::ZERO);
+    assert_eq!(Float64::MIN_POSITIVE.clamp01(), Float64::MIN_POSITIVE);
+    assert_eq!(
+        Float64::new(0.25).unwrap().clamp01(),
+        Float64::new(0.25).unwrap()
+    );
+    assert_eq!(Float64::ONE.clamp01(), Float64::ONE);
+    assert_eq!(Float64::ONE.next_up().clamp01(), Float64::ONE);
+    assert_eq!(Float64::MAX.clamp01(), Float64::ONE);
+}
+
+#[test]
+fn test_saturating_from() {
+    assert_eq!(Float32::saturating_from(f32::NAN), Float32::ZERO);
+    assert_eq!(Float32::saturating_from(f32::INFINITY), Float32::MAX);
+    assert_eq!(Float32::saturating_from(f32::NEG_INFINITY), Float32::MIN);
+    assert_eq!(Float32::saturating_from(1e-40), Float32::MIN_POSITIVE);
+    assert_eq!(Float32::saturating_from(-1e-40), Float32::MAX_NEGATIVE);
+    assert_eq!(Float32::saturating_from(-0.0), Float32::ZERO);
+    assert_eq!(Float32::saturating_from(2.5).get(), 2.5);
+
+    assert_eq!(Float64::saturating_from(f64::NAN), Float64::ZERO);
+    assert_eq!(Float64::saturating_from(f64::INFINITY), Float64::MAX);
+    assert_eq!(Float64::saturating_from(f64::NEG_INFINITY), Float64::MIN);
+    assert_eq!(Float64::saturating_from(1e-310), Float64::MIN_POSITIVE);
+    assert_eq!(Float64::saturating_from(-1e-310), Float64::MAX_NEGATIVE);
+    assert_eq!(Float64::saturating_from(-0.0), Float64::ZERO);
+    assert_eq!(Float64::saturating_from(2.5).get(), 2.5);
+}
+
+#[test]
+fn test_from_primitive_ref() {
+    let mut x = 1.5f32;
+    assert_eq!(Float32::from_primitive_ref(&x).unwrap().get(), 1.5);
+    *Float32::from_primitive_mut(&mut x).unwrap() *= Float32::new(2.0).unwrap();
+    assert_eq!(x, 3.0);
+    *Float32::from_primitive_mut(&mut x).unwrap() /= Float32::ZERO;
+    assert_eq!(x, f32::MAX);
+    assert_eq!(Float32::from_primitive_ref(&0.0), Some(&Float32::ZERO));
+    for &bad in &[f32::NAN, f32::INFINITY, f32::NEG_INFINITY, -0.0, 1e-40] {
+        let mut y = bad;
+        assert!(Float32::from_primitive_ref(&y).is_none());
+        assert!(Float32::from_primitive_mut(&mut y).is_none());
+    }
+
+    let mut x = 1.5f64;
+    assert_eq!(Float64::from_primitive_ref(&x).unwrap().get(), 1.5);
+    *Float64::from_primitive_mut(&mut x).unwrap() *= Float64::new(2.0).unwrap();
+    assert_eq!(x, 3.0);
+    *Float64::from_primitive_mut(&mut x).unwrap() /= Float64::ZERO;
+    assert_eq!(x, f64::MAX);
+    assert_eq!(Float64::from_primitive_ref(&0.0), Some(&Float64::ZERO));
+    for &bad in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -0.0, 1e-310] {
+        let mut y = bad;
+        assert!(Float64::from_primitive_ref(&y).is_none());
+        assert!(Float64::from_primitive_mut(&mut y).is_none());
+    }
+}
+
+#[test]
+fn test_from_slice() {
+    let mut a = [1.0f32, -2.0, 0.0];
+    let v = Float32::from_slice(&a).unwrap();
+    assert_eq!(v.as_ptr() as *const f32, a.as_ptr());
+    assert_eq!(
+        v,
+        [Float32::ONE, -Float32::ONE - Float32::ONE, Float32::ZERO]
+    );
+    for x in Float32::from_slice_mut(&mut a).unwrap() {
+        *x += Float32::ONE;
+    }
+    assert_eq!(a, [2.0, -1.0, 1.0]);
+    assert_eq!(Float32::from_slice(&[]), Some(&[][..]));
+    assert_eq!(Float32::from_slice_mut(&mut []), Some(&mut [][..]));
+    let mut b = [1.0, f32::NAN, f32::INFINITY];
+    assert_eq!(Float32::from_slice(&b), None);
+    assert_eq!(Float32::from_slice_mut(&mut b), None);
+    assert_eq!(
+        Float32::from_slice_sanitized(&b),
+        [Float32::ONE, Float32::ZERO, Float32::MAX]
+    );
+    assert!(Float32::from_slice_sanitized(&[]).is_empty());
+
+    let mut a = [1.0f64, -2.0, 0.0];
+    let v = Float64::from_slice(&a).unwrap();
+    assert_eq!(v.as_ptr() as *const f64, a.as_ptr());
+    assert_eq!(
+        v,
+        [Float64::ONE, -Float64::ONE - Float64::ONE, Float64::ZERO]
+    );
+    for x in Float64::from_slice_mut(&mut a).unwrap() {
+        *x += Float64::ONE;
+    }
+    assert_eq!(a, [2.0, -1.0, 1.0]);
+    assert_eq!(Float64::from_slice(&[]), Some(&[][..]));
+    assert_eq!(Float64::from_slice_mut(&mut []), Some(&mut [][..]));
+    let mut b = [1.0, f64::NAN, f64::INFINITY];
+    assert_eq!(Float64::from_slice(&b), None);
+    assert_eq!(Float64::from_slice_mut(&mut b), None);
+    assert_eq!(
+        Float64::from_slice_sanitized(&b),
+        [Float64::ONE, Float64::ZERO, Float64::MAX]
+    );
+    assert!(Float64::from_slice_sanitized(&[]).is_empty());
+}
+
+#[test]
+fn test_is_integer() {
+    assert!(Float32::new(3.0).unwrap().is_integer());
+    assert!(Float32::new(-7.0).unwrap().is_integer());
+    assert!(Float32::ZERO.is_integer());
+    assert!(Float32::MAX.is_integer());
+    assert!(!Float32::new(3.5).unwrap().is_integer());
+    assert!(!Float32::MIN_POSITIVE.is_integer());
+    assert_eq!(Float32::new(3.0).unwrap().to_integer_exact(), Some(3));
+    assert_eq!(Float32::new(-7.0).unwrap().to_integer_exact(), Some(-7));
+    assert_eq!(Float32::ZERO.to_integer_exact(), Some(0));
+    assert_eq!(Float32::new(3.5).unwrap().to_integer_exact(), None);
+    assert_eq!(
+        Float32::new(-9223372036854775808.0)
+            .unwrap()
+            .to_integer_exact(),
+        Some(i64::MIN)
+    );
+    assert_eq!(
+        Float32::new(9223372036854775808.0)
+            .unwrap()
+            .to_integer_exact(),
+        None
+    );
+    assert_eq!(Float32::MAX.to_integer_exact(), None);
+    assert_eq!(Float32::MIN.to_integer_exact(), None);
+
+    assert!(Float64::new(3.0).unwrap().is_integer());
+    assert!(Float64::new(-7.0).unwrap().is_integer());
+    assert!(Float64::ZERO.is_integer());
+    assert!(Float64::MAX.is_integer());
+    assert!(!Float64::new(3.5).unwrap().is_integer());
+    assert!(!Float64::MIN_POSITIVE.is_integer());
+    assert_eq!(Float64::new(3.0).unwrap().to_integer_exact(), Some(3));
+    assert_eq!(Float64::new(-7.0).unwrap().to_integer_exact(), Some(-7));
+    assert_eq!(Float64::ZERO.to_integer_exact(), Some(0));
+    assert_eq!(Float64::new(3.5).unwrap().to_integer_exact(), None);
+    assert_eq!(
+        Float64::new(-9223372036854775808.0)
+            .unwrap()
+            .to_integer_exact(),
+        Some(i64::MIN)
+    );
+    assert_eq!(
+        Float64::new(9223372036854774784.0)
+            .unwrap()
+            .to_integer_exact(),
+        Some(9223372036854774784)
+    );
+    assert_eq!(
+        Float64::new(9223372036854775808.0)
+            .unwrap()
+            .to_integer_exact(),
+        None
+    );
+    assert_eq!(Float64::MAX.to_integer_exact(), None);
+    assert_eq!(Float64::MIN.to_integer_exact(), None);
+}
+
+#[test]
+fn test_square_cube() {
+    assert_eq!(
+        Float32::new(3.0).unwrap().square(),
+        Float32::new(9.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-3.0).unwrap().square(),
+        Float32::new(9.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(3.0).unwrap().cube(),
+        Float32::new(27.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-3.0).unwrap().cube(),
+        Float32::new(-27.0).unwrap()
+    );
+    assert_eq!(Float32::ZERO.square(), Float32::ZERO);
+    assert_eq!(Float32::ZERO.cube(), Float32::ZERO);
+    assert_eq!(Float32::MAX.square(), Float32::MAX);
+    assert_eq!(Float32::MIN.square(), Float32::MAX);
+    assert_eq!(Float32::MIN.cube(), Float32::MIN);
+    assert_eq!(Float32::MIN_POSITIVE.square(), Float32::MIN_POSITIVE);
+    assert_eq!(Float32::MAX_NEGATIVE.cube(), Float32::MAX_NEGATIVE);
+
+    assert_eq!(
+        Float64::new(3.0).unwrap().square(),
+        Float64::new(9.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-3.0).unwrap().square(),
+        Float64::new(9.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(3.0).unwrap().cube(),
+        Float64::new(27.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-3.0).unwrap().cube(),
+        Float64::new(-27.0).unwrap()
+    );
+    assert_eq!(Float64::ZERO.square(), Float64::ZERO);
+    assert_eq!(Float64::ZERO.cube(), Float64::ZERO);
+    assert_eq!(Float64::MAX.square(), Float64::MAX);
+    assert_eq!(Float64::MIN.square(), Float64::MAX);
+    assert_eq!(Float64::MIN.cube(), Float64::MIN);
+    assert_eq!(Float64::MIN_POSITIVE.square(), Float64::MIN_POSITIVE);
+    assert_eq!(Float64::MAX_NEGATIVE.cube(), Float64::MAX_NEGATIVE);
+}
+
+#[test]
+fn test_to_exact_string() {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+
+    assert_eq!(Float32::new(0.1).unwrap().to_exact_string(), "0.1");
+    assert_eq!(Float32::ZERO.to_exact_string(), "0");
+    for &x in &[
+        Float32::MAX,
+        Float32::MIN,
+        Float32::MIN_POSITIVE,
+        Float32::MAX_NEGATIVE,
+    ] {
+        assert_eq!(x.to_exact_string().parse::<Float32>().unwrap(), x);
+    }
+    for _ in 0..10000 {
+        let x = Float32::from_bits(xorshift64(&mut state) as u32);
+        assert_eq!(x.to_exact_string().parse::<Float32>().unwrap(), x);
+    }
+
+    assert_eq!(Float64::new(0.1).unwrap().to_exact_string(), "0.1");
+    assert_eq!(Float64::ZERO.to_exact_string(), "0");
+    for &x in &[
+        Float64::MAX,
+        Float64::MIN,
+        Float64::MIN_POSITIVE,
+        Float64::MAX_NEGATIVE,
+    ] {
+        assert_eq!(x.to_exact_string().parse::<Float64>().unwrap(), x);
+    }
+    for _ in 0..10000 {
+        let x = Float64::from_bits(xorshift64(&mut state));
+        assert_eq!(x.to_exact_string().parse::<Float64>().unwrap(), x);
+    }
+}
+
+#[test]
+fn test_classify() {
+    assert_eq!(Float32::ZERO.classify(), FiniteCategory::Zero);
+    assert_eq!(Float32::MIN_POSITIVE.classify(), FiniteCategory::Normal);
+    assert_eq!(Float32::MAX_NEGATIVE.classify(), FiniteCategory::Normal);
+    assert_eq!(
+        Float32::new(1e-40).unwrap().classify(),
+        FiniteCategory::Normal
+    );
+    assert_eq!(
+        Float32::new(-2.5).unwrap().classify(),
+        FiniteCategory::Normal
+    );
+    assert_eq!(Float32::MAX.classify(), FiniteCategory::Normal);
+
+    assert_eq!(Float64::ZERO.classify(), FiniteCategory::Zero);
+    assert_eq!(Float64::MIN_POSITIVE.classify(), FiniteCategory::Normal);
+    assert_eq!(Float64::MAX_NEGATIVE.classify(), FiniteCategory::Normal);
+    assert_eq!(
+        Float64::new(1e-310).unwrap().classify(),
+        FiniteCategory::Normal
+    );
+    assert_eq!(
+        Float64::new(-2.5).unwrap().classify(),
+        FiniteCategory::Normal
+    );
+    assert_eq!(Float64::MAX.classify(), FiniteCategory::Normal);
+}
+
+#[test]
+fn test_negate_in_place() {
+    let mut z = Float32::ZERO;
+    z.negate_in_place();
+    assert!(z.get().is_sign_positive());
+    let a = [
+        Float32::MIN,
+        Float32::new(-1.5).unwrap(),
+        Float32::ZERO,
+        Float32::MIN_POSITIVE,
+    ];
+    let mut b = a;
+    for x in &mut b {
+        x.negate_in_place();
+    }
+    for (x, y) in a.iter().zip(&b) {
+        assert_eq!(-x, *y);
+        assert_eq!(-*x, *y);
+    }
+
+    let mut z = Float64::ZERO;
+    z.negate_in_place();
+    assert!(z.get().is_sign_positive());
+    let a = [
+        Float64::MIN,
+        Float64::new(-1.5).unwrap(),
+        Float64::ZERO,
+        Float64::MIN_POSITIVE,
+    ];
+    let mut b = a;
+    for x in &mut b {
+        x.negate_in_place();
+    }
+    for (x, y) in a.iter().zip(&b) {
+        assert_eq!(-x, *y);
+        assert_eq!(-*x, *y);
+    }
+}
+
+#[test]
+fn test_try_ops() {
+    assert_eq!(Float32::MAX.try_add(Float32::MAX), Err(OverflowError));
+    assert_eq!(Float32::MAX.try_add(Float32::MIN), Ok(Float32::ZERO));
+    assert_eq!(
+        Float32::new(1.5)
+            .unwrap()
+            .try_add(Float32::new(2.0).unwrap()),
+        Ok(Float32::new(3.5).unwrap())
+    );
+    assert_eq!(Float32::MIN.try_sub(Float32::MAX), Err(OverflowError));
+    assert_eq!(
+        Float32::new(1.5)
+            .unwrap()
+            .try_sub(Float32::new(2.0).unwrap()),
+        Ok(Float32::new(-0.5).unwrap())
+    );
+    assert_eq!(
+        Float32::MAX.try_mul(Float32::new(-2.0).unwrap()),
+        Err(OverflowError)
+    );
+    assert_eq!(
+        Float32::new(1.5)
+            .unwrap()
+            .try_mul(Float32::new(2.0).unwrap()),
+        Ok(Float32::new(3.0).unwrap())
+    );
+    assert_eq!(
+        Float32::MIN_POSITIVE.try_mul(Float32::MAX_NEGATIVE),
+        Ok(Float32::MAX_NEGATIVE)
+    );
+    assert_eq!(
+        Float32::MAX.try_div(Float32::new(0.5).unwrap()),
+        Err(OverflowError)
+    );
+    assert_eq!(
+        Float32::new(1.0).unwrap().try_div(Float32::ZERO),
+        Err(OverflowError)
+    );
+    assert_eq!(Float32::ZERO.try_div(Float32::ZERO), Err(OverflowError));
+    assert_eq!(
+        Float32::new(3.0)
+            .unwrap()
+            .try_div(Float32::new(2.0).unwrap()),
+        Ok(Float32::new(1.5).unwrap())
+    );
+    assert_eq!(
+        Float32::MIN_POSITIVE.try_div(Float32::MIN),
+        Ok(Float32::MAX_NEGATIVE)
+    );
+
+    assert_eq!(Float64::MAX.try_add(Float64::MAX), Err(OverflowError));
+    assert_eq!(Float64::MAX.try_add(Float64::MIN), Ok(Float64::ZERO));
+    assert_eq!(
+        Float64::new(1.5)
+            .unwrap()
+            .try_add(Float64::new(2.0).unwrap()),
+        Ok(Float64::new(3.5).unwrap())
+    );
+    assert_eq!(Float64::MIN.try_sub(Float64::MAX), Err(OverflowError));
+    assert_eq!(
+        Float64::new(1.5)
+            .unwrap()
+            .try_sub(Float64::new(2.0).unwrap()),
+        Ok(Float64::new(-0.5).unwrap())
+    );
+    assert_eq!(
+        Float64::MAX.try_mul(Float64::new(-2.0).unwrap()),
+        Err(OverflowError)
+    );
+    assert_eq!(
+        Float64::new(1.5)
+            .unwrap()
+            .try_mul(Float64::new(2.0).unwrap()),
+        Ok(Float64::new(3.0).unwrap())
+    );
+    assert_eq!(
+        Float64::MIN_POSITIVE.try_mul(Float64::MAX_NEGATIVE),
+        Ok(Float64::MAX_NEGATIVE)
+    );
+    assert_eq!(
+        Float64::MAX.try_div(Float64::new(0.5).unwrap()),
+        Err(OverflowError)
+    );
+    assert_eq!(
+        Float64::new(1.0).unwrap().try_div(Float64::ZERO),
+        Err(OverflowError)
+    );
+    assert_eq!(Float64::ZERO.try_div(Float64::ZERO), Err(OverflowError));
+    assert_eq!(
+        Float64::new(3.0)
+            .unwrap()
+            .try_div(Float64::new(2.0).unwrap()),
+        Ok(Float64::new(1.5).unwrap())
+    );
+    assert_eq!(
+        Float64::MIN_POSITIVE.try_div(Float64::MIN),
+        Ok(Float64::MAX_NEGATIVE)
+    );
+
+    assert_eq!(
+        OverflowError.to_string(),
+        "finite float arithmetic overflow"
+    );
+    let _: &dyn Error = &OverflowError;
+}
+
+#[test]
+fn test_from_f64_round() {
+    let check = |val: f64, nearest: Float32, toward_zero: Float32, up: Float32, down: Float32| {
+        assert_eq!(Float32::from_f64_round(val, RoundMode::Nearest), nearest);
+        assert_eq!(
+            Float32::from_f64_round(val, RoundMode::TowardZero),
+            toward_zero
+        );
+        assert_eq!(Float32::from_f64_round(val, RoundMode::Up), up);
+        assert_eq!(Float32::from_f64_round(val, RoundMode::Down), down);
+    };
+    let one = Float32::ONE;
+    let above_one = one.next_up();
+    let x = 1.0 + 2f64.powi(-30);
+    check(x, one, one, above_one, one);
+    check(-x, -one, -one, -one, -above_one);
+    let x = 1.0 + f64::from(f32::EPSILON) * 0.75;
+    check(x, above_one, one, above_one, one);
+    check(-x, -above_one, -one, -one, -above_one);
+    check(
+        1.5,
+        Float32::new(1.5).unwrap(),
+        Float32::new(1.5).unwrap(),
+        Float32::new(1.5).unwrap(),
+        Float32::new(1.5).unwrap(),
+    );
+    check(
+        0.0,
+        Float32::ZERO,
+        Float32::ZERO,
+        Float32::ZERO,
+        Float32::ZERO,
+    );
+    check(
+        f64::NAN,
+        Float32::ZERO,
+        Float32::ZERO,
+        Float32::ZERO,
+        Float32::ZERO,
+    );
+
+    // Overflow.
+    for &big in &[1e300, f64::INFINITY, f64::from(f32::MAX) * 1.5] {
+        check(big, Float32::MAX, Float32::MAX, Float32::MAX, Float32::MAX);
+        check(-big, Float32::MIN, Float32::MIN, Float32::MIN, Float32::MIN);
+    }
+    let x = f64::from(f32::MAX) + 1e30;
+    check(x, Float32::MAX, Float32::MAX, Float32::MAX, Float32::MAX);
+    let x = f64::from(f32::MAX) - 1e30;
+    check(
+        x,
+        Float32::MAX,
+        Float32::MAX.next_down(),
+        Float32::MAX,
+        Float32::MAX.next_down(),
+    );
+
+    // Underflow.
+    check(
+        1e-50,
+        Float32::MIN_POSITIVE,
+        Float32::ZERO,
+        Float32::MIN_POSITIVE,
+        Float32::ZERO,
+    );
+    check(
+        -1e-50,
+        Float32::MAX_NEGATIVE,
+        Float32::ZERO,
+        Float32::ZERO,
+        Float32::MAX_NEGATIVE,
+    );
+}
+
+#[test]
+fn test_interval() {
+    let a = Interval64::new(Float64::new(1.0).unwrap(), Float64::new(2.0).unwrap());
+    assert_eq!(a.lo(), Float64::new(1.0).unwrap());
+    assert_eq!(a.hi(), Float64::new(2.0).unwrap());
+    assert!(a.contains(Float64::new(1.0).unwrap()));
+    assert!(a.contains(Float64::new(1.5).unwrap()));
+    assert!(a.contains(Float64::new(2.0).unwrap()));
+    assert!(!a.contains(Float64::new(0.5).unwrap()));
+    assert!(!a.contains(Float64::new(1.0).unwrap().next_down()));
+    assert!(!a.contains(Float64::new(2.0).unwrap().next_up()));
+
+    // Addition: widths add, up to outward rounding.
+    let b = Interval64::new(Float64::new(0.1).unwrap(), Float64::new(0.3).unwrap());
+    let sum = a + b;
+    assert!(sum.contains(Float64::new(1.1).unwrap()) && sum.contains(Float64::new(2.3).unwrap()));
+    assert!(
+        sum.lo() <= Float64::new(1.1).unwrap()
+            && sum.lo() >= Float64::new(1.1).unwrap().next_down().next_down()
+    );
+    assert!(
+        sum.hi() >= Float64::new(2.3).unwrap()
+            && sum.hi() <= Float64::new(2.3).unwrap().next_up().next_up()
+    );
+    assert!((sum.width().get() - (a.width().get() + b.width().get())).abs() < 1e-14);
+    let exact = Interval64::point(Float64::new(0.1).unwrap())
+        + Interval64::point(Float64::new(0.2).unwrap());
+    assert!(exact.contains(Float64::new(0.1).unwrap() + Float64::new(0.2).unwrap()));
+
+    let diff = a - b;
+    assert!(diff.contains(Float64::new(0.7).unwrap()) && diff.contains(Float64::new(1.9).unwrap()));
+    assert!(
+        !diff.contains(Float64::new(0.6).unwrap()) && !diff.contains(Float64::new(2.0).unwrap())
+    );
+
+    // Multiplication across zero.
+    let p = Interval64::new(Float64::new(-2.0).unwrap(), Float64::new(3.0).unwrap())
+        * Interval64::new(Float64::new(-1.0).unwrap(), Float64::new(4.0).unwrap());
+    assert!(p.contains(Float64::new(-8.0).unwrap()) && p.contains(Float64::new(12.0).unwrap()));
+    assert!(!p.contains(Float64::new(-8.5).unwrap()) && !p.contains(Float64::new(12.5).unwrap()));
+    let p = Interval64::new(Float64::new(-2.0).unwrap(), Float64::new(-1.0).unwrap())
+        * Interval64::new(Float64::new(3.0).unwrap(), Float64::new(4.0).unwrap());
+    assert!(p.contains(Float64::new(-8.0).unwrap()) && p.contains(Float64::new(-3.0).unwrap()));
+    assert!(!p.contains(Float64::new(-2.5).unwrap()));
+
+    assert_eq!(
+        -a,
+        Interval64::new(Float64::new(-2.0).unwrap(), Float64::new(-1.0).unwrap())
+    );
+
+    // Width is rounded up only if inexact.
+    assert_eq!(a.width(), Float64::ONE);
+    assert_eq!(
+        Interval64::point(Float64::new(0.1).unwrap()).width(),
+        Float64::ZERO
+    );
+    assert_eq!(Interval64::point(Float64::MIN).width(), Float64::ZERO);
+    assert_eq!(
+        Interval64::new(Float64::new(-1e-30).unwrap(), Float64::new(1.0).unwrap()).width(),
+        Float64::ONE.next_up()
+    );
+    assert_eq!(
+        Interval64::new(Float64::new(1e-30).unwrap(), Float64::new(1.0).unwrap()).width(),
+        Float64::ONE
+    );
+    assert_eq!(
+        Interval64::new(Float64::MIN, Float64::MAX).width(),
+        Float64::MAX
+    );
+
+    // Saturation.
+    let big = Interval64::new(Float64::new(0.0).unwrap(), Float64::MAX)
+        + Interval64::new(Float64::new(1.0).unwrap(), Float64::MAX);
+    assert_eq!(big.hi(), Float64::MAX);
+}
+
+#[test]
+#[should_panic(expected = "interval with lo > hi")]
+fn test_interval_invalid() {
+    Interval64::new(Float64::ONE, Float64::ZERO);
+}
+
+#[test]
+fn test_as_primitive() {
+    let x = Float32::new(0.1).unwrap();
+    assert_eq!(x.as_f32(), 0.1f32);
+    assert_eq!(x.as_f64(), f64::from(0.1f32));
+    assert_ne!(x.as_f64(), 0.1f64);
+    assert_eq!(Float32::MAX.as_f64(), f64::from(f32::MAX));
+    assert_eq!(Float32::MIN_POSITIVE.as_f64(), f64::from(f32::MIN_POSITIVE));
+    assert_eq!(Float64::from(Float32::PI).as_f64(), Float32::PI.as_f64());
+
+    let x = Float64::new(0.1).unwrap();
+    assert_eq!(x.as_f64(), 0.1f64);
+    assert_eq!(Float64::MAX.as_f64(), f64::MAX);
+}
+
+#[test]
+fn test_checked_neg() {
+    for &x in &[
+        Float32::MIN,
+        Float32::MAX,
+        Float32::ZERO,
+        Float32::new(-1.5).unwrap(),
+    ] {
+        assert_eq!(x.checked_neg(), Some(-x));
+    }
+    assert_eq!(Float32::MIN.checked_neg(), Some(Float32::MAX));
+    assert!(Float32::ZERO
+        .checked_neg()
+        .unwrap()
+        .get()
+        .is_sign_positive());
+
+    for &x in &[
+        Float64::MIN,
+        Float64::MAX,
+        Float64::ZERO,
+        Float64::new(-1.5).unwrap(),
+    ] {
+        assert_eq!(x.checked_neg(), Some(-x));
+    }
+    assert_eq!(Float64::MIN.checked_neg(), Some(Float64::MAX));
+    assert!(Float64::ZERO
+        .checked_neg()
+        .unwrap()
+        .get()
+        .is_sign_positive());
+}
+
+#[test]
+fn test_parse_infinity() {
+    for s in &[
+        "inf",
+        "+inf",
+        "Inf",
+        "INF",
+        "infinity",
+        "+Infinity",
+        "INFINITY",
+        "iNfInItY",
+    ] {
+        assert_eq!(s.parse::<Float32>().unwrap(), Float32::MAX);
+        assert_eq!(s.parse::<Float64>().unwrap(), Float64::MAX);
+        let neg = format!("-{}", s.trim_start_matches('+'));
+        assert_eq!(neg.parse::<Float32>().unwrap(), Float32::MIN);
+        assert_eq!(neg.parse::<Float64>().unwrap(), Float64::MIN);
+    }
+    for s in &["in", "infin", "infinityy", "--inf", "+-inf", " inf", "inf "] {
+        assert!(s.parse::<Float32>().is_err());
+        assert!(s.parse::<Float64>().is_err());
+    }
+}
+
+#[test]
+fn test_percent() {
+    assert_eq!(
+        Float32::new(0.5).unwrap().to_percent(),
+        Float32::new(50.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-1.25).unwrap().to_percent(),
+        Float32::new(-125.0).unwrap()
+    );
+    assert_eq!(
+        Float32::from_percent(Float32::new(50.0).unwrap()),
+        Float32::new(0.5).unwrap()
+    );
+    assert_eq!(
+        Float32::from_percent(Float32::new(-125.0).unwrap()),
+        Float32::new(-1.25).unwrap()
+    );
+    assert_eq!(Float32::ZERO.to_percent(), Float32::ZERO);
+    assert_eq!(Float32::MAX.to_percent(), Float32::MAX);
+    assert_eq!(Float32::MIN.to_percent(), Float32::MIN);
+    assert_eq!(
+        Float32::from_percent(Float32::MIN_POSITIVE),
+        Float32::MIN_POSITIVE
+    );
+    assert_eq!(
+        Float32::from_percent(Float32::MAX_NEGATIVE),
+        Float32::MAX_NEGATIVE
+    );
+
+    assert_eq!(
+        Float64::new(0.5).unwrap().to_percent(),
+        Float64::new(50.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-1.25).unwrap().to_percent(),
+        Float64::new(-125.0).unwrap()
+    );
+    assert_eq!(
+        Float64::from_percent(Float64::new(50.0).unwrap()),
+        Float64::new(0.5).unwrap()
+    );
+    assert_eq!(
+        Float64::from_percent(Float64::new(-125.0).unwrap()),
+        Float64::new(-1.25).unwrap()
+    );
+    assert_eq!(Float64::ZERO.to_percent(), Float64::ZERO);
+    assert_eq!(Float64::MAX.to_percent(), Float64::MAX);
+    assert_eq!(Float64::MIN.to_percent(), Float64::MIN);
+    assert_eq!(
+        Float64::from_percent(Float64::MIN_POSITIVE),
+        Float64::MIN_POSITIVE
+    );
+    assert_eq!(
+        Float64::from_percent(Float64::MAX_NEGATIVE),
+        Float64::MAX_NEGATIVE
+    );
+}
+
+#[test]
+fn test_signum_or() {
+    let one = Float32::ONE;
+    assert_eq!(Float32::ZERO.signum_or(one), one);
+    assert_eq!(Float32::ZERO.signum_or(-one), -one);
+    assert_eq!(Float32::ZERO.signum_or(Float32::ZERO), Float32::ZERO);
+    assert_eq!(Float32::MIN_POSITIVE.signum_or(-one), one);
+    assert_eq!(Float32::MAX_NEGATIVE.signum_or(one), -one);
+    assert_eq!(Float32::MAX.signum_or(-one), one);
+    assert_eq!(Float32::MIN.signum_or(one), -one);
+
+    let one = Float64::ONE;
+    assert_eq!(Float64::ZERO.signum_or(one), one);
+    assert_eq!(Float64::ZERO.signum_or(-one), -one);
+    assert_eq!(Float64::ZERO.signum_or(Float64::ZERO), Float64::ZERO);
+    assert_eq!(Float64::MIN_POSITIVE.signum_or(-one), one);
+    assert_eq!(Float64::MAX_NEGATIVE.signum_or(one), -one);
+    assert_eq!(Float64::MAX.signum_or(-one), one);
+    assert_eq!(Float64::MIN.signum_or(one), -one);
+}
+
+#[test]
+fn test_sum_slice() {
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+
+    // Dyadic values, so that all sums are exact.
+    let a: Vec<Float64> = (0..1_000_000)
+        .map(|_| Float64::new((xorshift64(&mut state) % 2001) as f64 / 16.0 - 62.5).unwrap())
+        .collect();
+    for n in 0..20 {
+        assert_eq!(sum_slice(&a[..n]), a[..n].iter().sum::<Float64>());
+    }
+    assert_eq!(sum_slice(&a), a.iter().sum::<Float64>());
+    let sat = [Float64::MAX, Float64::MAX, Float64::MIN, Float64::ONE];
+    assert_eq!(sum_slice(&sat), sat.iter().sum::<Float64>());
+    assert_eq!(sum_slice(&[Float64::MIN; 20]), Float64::MIN);
+    assert_eq!(sum_slice(&[]), Float64::ZERO);
+    // No accumulator overflows, but the sequential sum saturates.
+    let mut sat = [Float64::ZERO; 10];
+    sat[0] = Float64::MAX;
+    sat[1] = Float64::MAX;
+    sat[8] = Float64::MIN;
+    sat[9] = Float64::MIN;
+    assert_eq!(sum_slice(&sat), Float64::MIN);
+    assert_eq!(sum_slice(&sat), sat.iter().sum::<Float64>());
+}
+
+#[test]
+fn test_product_slice() {
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+
+    // Powers of two, so that all products are exact unless they overflow or underflow.
+    let a: Vec<Float64> = (0..1_000_000)
+        .map(|_| {
+            let x = Float64::new(2.0f64.powi((xorshift64(&mut state) % 7) as i32 - 3)).unwrap();
+            if xorshift64(&mut state) % 2 == 0 {
+                x
+            } else {
+                -x
+            }
+        })
+        .collect();
+    for n in 0..20 {
+        assert_eq!(product_slice(&a[..n]), a[..n].iter().product::<Float64>());
+    }
+    for &n in &[100, 1000, 10007, 1_000_000] {
+        assert_eq!(product_slice(&a[..n]), a[..n].iter().product::<Float64>());
+    }
+    assert_eq!(product_slice(&[]), Float64::ONE);
+    assert_eq!(
+        product_slice(&[Float64::MAX, Float64::ZERO, Float64::MAX]),
+        Float64::ZERO
+    );
+
+    // Saturation along the way.
+    let quarter = Float64::new(0.25).unwrap();
+    let two = Float64::new(2.0).unwrap();
+    let sat = [Float64::MAX, two, quarter];
+    assert_eq!(product_slice(&sat), Float64::MAX * quarter);
+    assert_eq!(product_slice(&sat), sat.iter().product::<Float64>());
+    let sat = [Float64::MIN_POSITIVE, quarter, -two];
+    assert_eq!(product_slice(&sat), Float64::MIN_POSITIVE * -two);
+    assert_eq!(product_slice(&sat), sat.iter().product::<Float64>());
+}
+
+#[test]
+fn test_min_max_element() {
+    let a = [
+        Float64::new(1.5).unwrap(),
+        Float64::new(-3.0).unwrap(),
+        Float64::ZERO,
+        Float64::new(7.0).unwrap(),
+        Float64::new(-0.5).unwrap(),
+    ];
+    assert_eq!(min_element(&a), Some(Float64::new(-3.0).unwrap()));
+    assert_eq!(max_element(&a), Some(Float64::new(7.0).unwrap()));
+    assert_eq!(
+        min_element(&[Float64::new(2.0).unwrap()]),
+        Some(Float64::new(2.0).unwrap())
+    );
+    assert_eq!(
+        max_element(&[Float64::new(2.0).unwrap()]),
+        Some(Float64::new(2.0).unwrap())
+    );
+    assert_eq!(min_element(&[]), None);
+    assert_eq!(max_element(&[]), None);
+    let b = [Float64::MAX, Float64::MIN, Float64::MAX_NEGATIVE];
+    assert_eq!(min_element(&b), Some(Float64::MIN));
+    assert_eq!(max_element(&b), Some(Float64::MAX));
+}
+
+#[test]
+fn test_sort_floats() {
+    let mut a = [
+        Float64::MIN_POSITIVE,
+        Float64::new(2.5).unwrap(),
+        Float64::MAX,
+        Float64::ZERO,
+        Float64::new(-1.0).unwrap(),
+        Float64::MIN,
+        Float64::MAX_NEGATIVE,
+        Float64::new(2.5).unwrap(),
+    ];
+    sort_floats(&mut a);
+    assert_eq!(
+        a,
+        [
+            Float64::MIN,
+            Float64::new(-1.0).unwrap(),
+            Float64::MAX_NEGATIVE,
+            Float64::ZERO,
+            Float64::MIN_POSITIVE,
+            Float64::new(2.5).unwrap(),
+            Float64::new(2.5).unwrap(),
+            Float64::MAX,
+        ]
+    );
+    sort_floats(&mut []);
+}
+
+#[test]
+fn test_unlerp() {
+    let (a, b) = (Float32::new(2.0).unwrap(), Float32::new(-6.0).unwrap());
+    assert_eq!(a.unlerp(a, b), Float32::ZERO);
+    assert_eq!(b.unlerp(a, b), Float32::ONE);
+    assert_eq!(
+        Float32::new(-2.0).unwrap().unlerp(a, b),
+        Float32::new(0.5).unwrap()
+    );
+    assert_eq!(
+        Float32::lerp(a, b, Float32::new(0.25).unwrap()).unlerp(a, b),
+        Float32::new(0.25).unwrap()
+    );
+    assert_eq!(
+        Float32::new(10.0).unwrap().unlerp(a, b),
+        Float32::new(-1.0).unwrap()
+    );
+    assert_eq!(Float32::new(5.0).unwrap().unlerp(a, a), Float32::ZERO);
+    assert_eq!(a.unlerp(a, a), Float32::ZERO);
+    assert_eq!(
+        Float32::MAX.unlerp(Float32::ZERO, Float32::MIN_POSITIVE),
+        Float32::MAX
+    );
+
+    let (a, b) = (Float64::new(2.0).unwrap(), Float64::new(-6.0).unwrap());
+    assert_eq!(a.unlerp(a, b), Float64::ZERO);
+    assert_eq!(b.unlerp(a, b), Float64::ONE);
+    assert_eq!(
+        Float64::new(-2.0).unwrap().unlerp(a, b),
+        Float64::new(0.5).unwrap()
+    );
+    assert_eq!(
+        Float64::lerp(a, b, Float64::new(0.25).unwrap()).unlerp(a, b),
+        Float64::new(0.25).unwrap()
+    );
+    assert_eq!(
+        Float64::new(10.0).unwrap().unlerp(a, b),
+        Float64::new(-1.0).unwrap()
+    );
+    assert_eq!(Float64::new(5.0).unwrap().unlerp(a, a), Float64::ZERO);
+    assert_eq!(a.unlerp(a, a), Float64::ZERO);
+    assert_eq!(
+        Float64::MAX.unlerp(Float64::ZERO, Float64::MIN_POSITIVE),
+        Float64::MAX
+    );
+
+    // Wide ranges where b - a overflows.
+    assert_eq!(
+        Float32::ZERO.unlerp(Float32::MIN, Float32::MAX),
+        Float32::new(0.5).unwrap()
+    );
+    assert_eq!(
+        Float32::MAX.unlerp(Float32::MIN, Float32::MAX),
+        Float32::ONE
+    );
+    assert_eq!(
+        Float32::MIN_POSITIVE.unlerp(Float32::MAX, Float32::MIN),
+        Float32::new(0.5).unwrap()
+    );
+    assert_eq!(
+        Float64::ZERO.unlerp(Float64::MIN, Float64::MAX),
+        Float64::new(0.5).unwrap()
+    );
+    assert_eq!(
+        Float64::MAX.unlerp(Float64::MIN, Float64::MAX),
+        Float64::ONE
+    );
+    assert_eq!(
+        Float64::MIN_POSITIVE.unlerp(Float64::MAX, Float64::MIN),
+        Float64::new(0.5).unwrap()
+    );
+}
+
+#[test]
+fn test_remap() {
+    let (zero, one) = (Float32::ZERO, Float32::ONE);
+    assert_eq!(zero.remap(zero, one, -one, one), -one);
+    assert_eq!(Float32::new(0.5).unwrap().remap(zero, one, -one, one), zero);
+    assert_eq!(
+        Float32::new(0.75).unwrap().remap(zero, one, -one, one),
+        Float32::new(0.5).unwrap()
+    );
+    assert_eq!(one.remap(zero, one, -one, one), one);
+    assert_eq!(
+        Float32::new(2.0).unwrap().remap(zero, one, -one, one),
+        Float32::new(3.0).unwrap()
+    );
+    // Reversed output range.
+    assert_eq!(
+        Float32::new(0.25).unwrap().remap(
+            zero,
+            one,
+            Float32::new(10.0).unwrap(),
+            Float32::new(-10.0).unwrap()
+        ),
+        Float32::new(5.0).unwrap()
+    );
+    assert_eq!(
+        one.remap(
+            zero,
+            one,
+            Float32::new(10.0).unwrap(),
+            Float32::new(-10.0).unwrap()
+        ),
+        Float32::new(-10.0).unwrap()
+    );
+    // Degenerate input range.
+    assert_eq!(
+        Float32::new(7.0).unwrap().remap(
+            one,
+            one,
+            Float32::new(3.0).unwrap(),
+            Float32::new(4.0).unwrap()
+        ),
+        Float32::new(3.0).unwrap()
+    );
+    assert_eq!(
+        Float32::MAX.remap(zero, one, zero, Float32::MAX),
+        Float32::MAX
+    );
+    assert_eq!(
+        Float32::MIN.remap(zero, one, zero, Float32::MAX),
+        Float32::MIN
+    );
+
+    let (zero, one) = (Float64::ZERO, Float64::ONE);
+    assert_eq!(zero.remap(zero, one, -one, one), -one);
+    assert_eq!(Float64::new(0.5).unwrap().remap(zero, one, -one, one), zero);
+    assert_eq!(
+        Float64::new(0.75).unwrap().remap(zero, one, -one, one),
+        Float64::new(0.5).unwrap()
+    );
+    assert_eq!(one.remap(zero, one, -one, one), one);
+    assert_eq!(
+        Float64::new(2.0).unwrap().remap(zero, one, -one, one),
+        Float64::new(3.0).unwrap()
+    );
+    // Reversed output range.
+    assert_eq!(
+        Float64::new(0.25).unwrap().remap(
+            zero,
+            one,
+            Float64::new(10.0).unwrap(),
+            Float64::new(-10.0).unwrap()
+        ),
+        Float64::new(5.0).unwrap()
+    );
+    assert_eq!(
+        one.remap(
+            zero,
+            one,
+            Float64::new(10.0).unwrap(),
+            Float64::new(-10.0).unwrap()
+        ),
+        Float64::new(-10.0).unwrap()
+    );
+    // Degenerate input range.
+    assert_eq!(
+        Float64::new(7.0).unwrap().remap(
+            one,
+            one,
+            Float64::new(3.0).unwrap(),
+            Float64::new(4.0).unwrap()
+        ),
+        Float64::new(3.0).unwrap()
+    );
+    assert_eq!(
+        Float64::MAX.remap(zero, one, zero, Float64::MAX),
+        Float64::MAX
+    );
+    assert_eq!(
+        Float64::MIN.remap(zero, one, zero, Float64::MAX),
+        Float64::MIN
+    );
+
+    // Wide ranges where differences overflow.
+    assert_eq!(
+        Float32::ZERO.remap(Float32::MIN, Float32::MAX, Float32::MAX, Float32::MIN),
+        Float32::ZERO
+    );
+    assert_eq!(
+        Float32::ZERO.remap(Float32::MIN, Float32::MAX, Float32::ZERO, Float32::ONE),
+        Float32::new(0.5).unwrap()
+    );
+    assert_eq!(
+        Float64::ZERO.remap(Float64::MIN, Float64::MAX, Float64::MAX, Float64::MIN),
+        Float64::ZERO
+    );
+    assert_eq!(
+        Float64::ZERO.remap(Float64::MIN, Float64::MAX, Float64::ZERO, Float64::ONE),
+        Float64::new(0.5).unwrap()
+    );
+}
+
+#[test]
+fn test_wrap_angle() {
+    let pi = Float32::PI;
+    let tau = Float32::TAU;
+    assert_eq!(Float32::ZERO.wrap_to_pi(), Float32::ZERO);
+    assert_eq!(pi.wrap_to_pi(), pi);
+    assert_eq!((-pi).wrap_to_pi(), pi);
+    assert_eq!(pi.next_up().wrap_to_pi(), pi.next_up() - tau);
+    assert!(
+        (Float32::new(4.0).unwrap().wrap_to_pi().get() - (4.0 - std::f32::consts::PI * 2.0)).abs()
+            < 1e-6
+    );
+    assert!(
+        (Float32::new(-4.0).unwrap().wrap_to_pi().get() - (-4.0 + std::f32::consts::PI * 2.0))
+            .abs()
+            < 1e-6
+    );
+    assert_eq!(Float32::ZERO.wrap_to_2pi(), Float32::ZERO);
+    assert_eq!(tau.wrap_to_2pi(), Float32::ZERO);
+    assert_eq!(
+        (tau * Float32::new(1024.0).unwrap()).wrap_to_2pi(),
+        Float32::ZERO
+    );
+    assert_eq!(
+        (-tau * Float32::new(1024.0).unwrap()).wrap_to_2pi(),
+        Float32::ZERO
+    );
+    assert!(
+        (Float32::new(-1.0).unwrap().wrap_to_2pi().get() - (std::f32::consts::PI * 2.0 - 1.0))
+            .abs()
+            < 1e-6
+    );
+    assert_eq!(Float32::MAX_NEGATIVE.wrap_to_2pi(), Float32::ZERO);
+    for &x in &[
+        Float32::new(1e10).unwrap(),
+        Float32::new(-1e10).unwrap(),
+        Float32::MAX,
+        Float32::MIN,
+        Float32::new(-1e-30).unwrap(),
+        Float32::new(123.0).unwrap(),
+    ] {
+        let r = x.wrap_to_2pi();
+        assert!(r >= Float32::ZERO && r < tau);
+        let r = x.wrap_to_pi();
+        assert!(r > -pi && r <= pi);
+    }
+
+    let pi = Float64::PI;
+    let tau = Float64::TAU;
+    assert_eq!(Float64::ZERO.wrap_to_pi(), Float64::ZERO);
+    assert_eq!(pi.wrap_to_pi(), pi);
+    assert_eq!((-pi).wrap_to_pi(), pi);
+    assert_eq!(pi.next_up().wrap_to_pi(), pi.next_up() - tau);
+    assert!(
+        (Float64::new(4.0).unwrap().wrap_to_pi().get() - (4.0 - std::f64::consts::PI * 2.0)).abs()
+            < 1e-14
+    );
+    assert!(
+        (Float64::new(-4.0).unwrap().wrap_to_pi().get() - (-4.0 + std::f64::consts::PI * 2.0))
+            .abs()
+            < 1e-14
+    );
+    assert_eq!(Float64::ZERO.wrap_to_2pi(), Float64::ZERO);
+    assert_eq!(tau.wrap_to_2pi(), Float64::ZERO);
+    assert_eq!(
+        (tau * Float64::new(1024.0).unwrap()).wrap_to_2pi(),
+        Float64::ZERO
+    );
+    assert_eq!(
+        (-tau * Float64::new(1024.0).unwrap()).wrap_to_2pi(),
+        Float64::ZERO
+    );
+    assert!(
+        (Float64::new(-1.0).unwrap().wrap_to_2pi().get() - (std::f64::consts::PI * 2.0 - 1.0))
+            .abs()
+            < 1e-14
+    );
+    assert_eq!(Float64::MAX_NEGATIVE.wrap_to_2pi(), Float64::ZERO);
+    for &x in &[
+        Float64::new(1e10).unwrap(),
+        Float64::new(-1e10).unwrap(),
+        Float64::MAX,
+        Float64::MIN,
+        Float64::new(-1e-30).unwrap(),
+        Float64::new(123.0).unwrap(),
+    ] {
+        let r = x.wrap_to_2pi();
+        assert!(r >= Float64::ZERO && r < tau);
+        let r = x.wrap_to_pi();
+        assert!(r > -pi && r <= pi);
+    }
+}
+
+#[test]
+fn test_checked_sqrt() {
+    assert_eq!(
+        Float32::new(6.25).unwrap().checked_sqrt(),
+        Some(Float32::new(2.5).unwrap())
+    );
+    assert_eq!(Float32::ZERO.checked_sqrt(), Some(Float32::ZERO));
+    assert_eq!(Float32::MAX.checked_sqrt(), Some(Float32::MAX.sqrt()));
+    assert_eq!(Float32::MAX_NEGATIVE.checked_sqrt(), None);
+    assert_eq!(Float32::MIN.checked_sqrt(), None);
+
+    assert_eq!(
+        Float64::new(6.25).unwrap().checked_sqrt(),
+        Some(Float64::new(2.5).unwrap())
+    );
+    assert_eq!(Float64::ZERO.checked_sqrt(), Some(Float64::ZERO));
+    assert_eq!(Float64::MAX.checked_sqrt(), Some(Float64::MAX.sqrt()));
+    assert_eq!(Float64::MAX_NEGATIVE.checked_sqrt(), None);
+    assert_eq!(Float64::MIN.checked_sqrt(), None);
+}
+
+#[test]
+fn test_checked_ln_powf() {
+    assert_eq!(Float32::ONE.checked_ln(), Some(Float32::ZERO));
+    assert_eq!(
+        Float32::new(4.0).unwrap().checked_ln(),
+        Some(Float32::new(4.0).unwrap().ln())
+    );
+    assert_eq!(
+        Float32::MIN_POSITIVE.checked_ln(),
+        Some(Float32::MIN_POSITIVE.ln())
+    );
+    assert_eq!(Float32::ZERO.checked_ln(), None);
+    assert_eq!(Float32::MAX_NEGATIVE.checked_ln(), None);
+    assert_eq!(Float32::new(-1.0).unwrap().checked_ln(), None);
+    assert_eq!(
+        Float32::new(2.0)
+            .unwrap()
+            .checked_powf(Float32::new(3.0).unwrap()),
+        Some(Float32::new(8.0).unwrap())
+    );
+    assert_eq!(
+        Float32::new(-2.0)
+            .unwrap()
+            .checked_powf(Float32::new(3.0).unwrap()),
+        Some(Float32::new(-8.0).unwrap())
+    );
+    assert_eq!(
+        Float32::new(4.0)
+            .unwrap()
+            .checked_powf(Float32::new(0.5).unwrap()),
+        Some(Float32::new(2.0).unwrap())
+    );
+    assert_eq!(
+        Float32::new(-4.0)
+            .unwrap()
+            .checked_powf(Float32::new(0.5).unwrap()),
+        None
+    );
+    assert_eq!(
+        Float32::MAX_NEGATIVE.checked_powf(Float32::new(-1.5).unwrap()),
+        None
+    );
+    assert_eq!(
+        Float32::ZERO.checked_powf(Float32::new(-1.0).unwrap()),
+        Some(Float32::MAX)
+    );
+    assert_eq!(
+        Float32::new(10.0)
+            .unwrap()
+            .checked_powf(Float32::new(100.0).unwrap()),
+        Some(Float32::MAX)
+    );
+
+    assert_eq!(Float64::ONE.checked_ln(), Some(Float64::ZERO));
+    assert_eq!(
+        Float64::new(4.0).unwrap().checked_ln(),
+        Some(Float64::new(4.0).unwrap().ln())
+    );
+    assert_eq!(
+        Float64::MIN_POSITIVE.checked_ln(),
+        Some(Float64::MIN_POSITIVE.ln())
+    );
+    assert_eq!(Float64::ZERO.checked_ln(), None);
+    assert_eq!(Float64::MAX_NEGATIVE.checked_ln(), None);
+    assert_eq!(Float64::new(-1.0).unwrap().checked_ln(), None);
+    assert_eq!(
+        Float64::new(2.0)
+            .unwrap()
+            .checked_powf(Float64::new(3.0).unwrap()),
+        Some(Float64::new(8.0).unwrap())
+    );
+    assert_eq!(
+        Float64::new(-2.0)
+            .unwrap()
+            .checked_powf(Float64::new(3.0).unwrap()),
+        Some(Float64::new(-8.0).unwrap())
+    );
+    assert_eq!(
+        Float64::new(4.0)
+            .unwrap()
+            .checked_powf(Float64::new(0.5).unwrap()),
+        Some(Float64::new(2.0).unwrap())
+    );
+    assert_eq!(
+        Float64::new(-4.0)
+            .unwrap()
+            .checked_powf(Float64::new(0.5).unwrap()),
+        None
+    );
+    assert_eq!(
+        Float64::MAX_NEGATIVE.checked_powf(Float64::new(-1.5).unwrap()),
+        None
+    );
+    assert_eq!(
+        Float64::ZERO.checked_powf(Float64::new(-1.0).unwrap()),
+        Some(Float64::MAX)
+    );
+    assert_eq!(
+        Float64::new(10.0)
+            .unwrap()
+            .checked_powf(Float64::new(400.0).unwrap()),
+        Some(Float64::MAX)
+    );
+}
+
+#[test]
+fn test_euclid() {
+    assert_eq!(
+        Float32::new(-7.0)
+            .unwrap()
+            .rem_euclid(Float32::new(3.0).unwrap()),
+        Float32::new(2.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-7.0)
+            .unwrap()
+            .div_euclid(Float32::new(3.0).unwrap()),
+        Float32::new(-3.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(7.0)
+            .unwrap()
+            .rem_euclid(Float32::new(3.0).unwrap()),
+        Float32::new(1.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(7.0)
+            .unwrap()
+            .div_euclid(Float32::new(3.0).unwrap()),
+        Float32::new(2.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-7.0)
+            .unwrap()
+            .rem_euclid(Float32::new(-3.0).unwrap()),
+        Float32::new(2.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-7.0)
+            .unwrap()
+            .div_euclid(Float32::new(-3.0).unwrap()),
+        Float32::new(3.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-7.0).unwrap().rem_euclid(Float32::ZERO),
+        Float32::ZERO
+    );
+    assert_eq!(
+        Float32::new(-7.0).unwrap().div_euclid(Float32::ZERO),
+        Float32::ZERO
+    );
+
+    assert_eq!(
+        Float64::new(-7.0)
+            .unwrap()
+            .rem_euclid(Float64::new(3.0).unwrap()),
+        Float64::new(2.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-7.0)
+            .unwrap()
+            .div_euclid(Float64::new(3.0).unwrap()),
+        Float64::new(-3.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(7.0)
+            .unwrap()
+            .rem_euclid(Float64::new(3.0).unwrap()),
+        Float64::new(1.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(7.0)
+            .unwrap()
+            .div_euclid(Float64::new(3.0).unwrap()),
+        Float64::new(2.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-7.0)
+            .unwrap()
+            .rem_euclid(Float64::new(-3.0).unwrap()),
+        Float64::new(2.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-7.0)
+            .unwrap()
+            .div_euclid(Float64::new(-3.0).unwrap()),
+        Float64::new(3.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-7.0).unwrap().rem_euclid(Float64::ZERO),
+        Float64::ZERO
+    );
+    assert_eq!(
+        Float64::new(-7.0).unwrap().div_euclid(Float64::ZERO),
+        Float64::ZERO
+    );
+}
+
+#[test]
+fn test_float_predicates() {
+    for &x in &[
+        Float32::ZERO,
+        Float32::MIN,
+        Float32::MAX,
+        Float32::new(1.5).unwrap(),
+    ] {
+        assert!(x.is_finite());
+        assert!(!x.is_infinite());
+        assert!(!x.is_nan());
+        assert_eq!(x.is_normal(), x.get().is_normal());
+    }
+    assert!(!Float32::ZERO.is_normal());
+    assert!(Float32::MIN_POSITIVE.is_normal());
+
+    for &x in &[
+        Float64::ZERO,
+        Float64::MIN,
+        Float64::MAX,
+        Float64::new(1.5).unwrap(),
+    ] {
+        assert!(x.is_finite());
+        assert!(!x.is_infinite());
+        assert!(!x.is_nan());
+        assert_eq!(x.is_normal(), x.get().is_normal());
+    }
+    assert!(!Float64::ZERO.is_normal());
+    assert!(Float64::MIN_POSITIVE.is_normal());
+}
+
+#[test]
+fn test_bytes() {
+    for &x in &[
+        Float32::ZERO,
+        Float32::MIN,
+        Float32::MAX,
+        Float32::MIN_POSITIVE,
+        Float32::new(-1.5).unwrap(),
+    ] {
+        assert_eq!(Float32::from_le_bytes(x.to_le_bytes()), x);
+        assert_eq!(Float32::from_be_bytes(x.to_be_bytes()), x);
+    }
+    assert_eq!(Float32::new(1.0).unwrap().to_be_bytes(), [0x3f, 0x80, 0, 0]);
+    assert_eq!(Float32::new(1.0).unwrap().to_le_bytes(), [0, 0, 0x80, 0x3f]);
+    assert_eq!(
+        Float32::from_le_bytes(f32::NAN.to_le_bytes()),
+        Float32::ZERO
+    );
+    assert_eq!(
+        Float32::from_be_bytes(f32::NAN.to_be_bytes()),
+        Float32::ZERO
+    );
+    assert_eq!(
+        Float32::from_be_bytes(f32::NEG_INFINITY.to_be_bytes()),
+        Float32::MIN
+    );
+    assert_eq!(
+        Float32::from_le_bytes((-0.0f32).to_le_bytes()),
+        Float32::ZERO
+    );
+
+    for &x in &[
+        Float64::ZERO,
+        Float64::MIN,
+        Float64::MAX,
+        Float64::MIN_POSITIVE,
+        Float64::new(-1.5).unwrap(),
+    ] {
+        assert_eq!(Float64::from_le_bytes(x.to_le_bytes()), x);
+        assert_eq!(Float64::from_be_bytes(x.to_be_bytes()), x);
+    }
+    assert_eq!(
+        Float64::new(1.0).unwrap().to_be_bytes(),
+        [0x3f, 0xf0, 0, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(
+        Float64::from_le_bytes(f64::NAN.to_le_bytes()),
+        Float64::ZERO
+    );
+    assert_eq!(
+        Float64::from_be_bytes(f64::NAN.to_be_bytes()),
+        Float64::ZERO
+    );
+    assert_eq!(
+        Float64::from_le_bytes(f64::INFINITY.to_le_bytes()),
+        Float64::MAX
+    );
+    assert_eq!(
+        Float64::from_be_bytes((-0.0f64).to_be_bytes()),
+        Float64::ZERO
+    );
+}
+
+#[test]
+fn test_cmp_cross_width() {
+    let a = Float32::new(0.1).unwrap();
+    let b = Float64::new(0.1).unwrap();
+    assert!(a != b);
+    assert!(b != a);
+    // 0.1f32 is slightly larger than 0.1f64.
+    assert!(a > b);
+    assert!(b < a);
+    assert_eq!(a.partial_cmp(&b), Some(Ordering::Greater));
+    assert_eq!(b.partial_cmp(&a), Some(Ordering::Less));
+
+    let a = Float32::new(0.5).unwrap();
+    let b = Float64::new(0.5).unwrap();
+    assert!(a == b);
+    assert!(b == a);
+    assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+    assert_eq!(b.partial_cmp(&a), Some(Ordering::Equal));
+
+    assert!(Float32::MAX < Float64::MAX);
+    assert!(Float32::MIN > Float64::MIN);
+    assert!(Float32::ZERO == Float64::ZERO);
+}
+
+#[test]
+fn test_nextafter() {
+    let one = Float32::ONE;
+    let two = Float32::new(2.0).unwrap();
+    assert_eq!(one.nextafter(two), one.next_up());
+    assert_eq!(two.nextafter(one), two.next_down());
+    assert_eq!(one.nextafter(one), one);
+    assert_eq!(Float32::ZERO.nextafter(one), Float32::MIN_POSITIVE);
+    assert_eq!(Float32::ZERO.nextafter(-one), Float32::MAX_NEGATIVE);
+    assert_eq!(Float32::MIN_POSITIVE.nextafter(-one), Float32::ZERO);
+    assert_eq!(Float32::MAX_NEGATIVE.nextafter(one), Float32::ZERO);
+    assert_eq!(Float32::MAX.nextafter(Float32::MAX), Float32::MAX);
+
+    let one = Float64::ONE;
+    let two = Float64::new(2.0).unwrap();
+    assert_eq!(one.nextafter(two), one.next_up());
+    assert_eq!(two.nextafter(one), two.next_down());
+    assert_eq!(one.nextafter(one), one);
+    assert_eq!(Float64::ZERO.nextafter(one), Float64::MIN_POSITIVE);
+    assert_eq!(Float64::ZERO.nextafter(-one), Float64::MAX_NEGATIVE);
+    assert_eq!(Float64::MIN_POSITIVE.nextafter(-one), Float64::ZERO);
+    assert_eq!(Float64::MAX_NEGATIVE.nextafter(one), Float64::ZERO);
+    assert_eq!(Float64::MIN.nextafter(Float64::MIN), Float64::MIN);
+}
+
+#[test]
+fn test_geometric_mean() {
+    let g = geometric_mean(&[
+        Float64::new(1.0).unwrap(),
+        Float64::new(2.0).unwrap(),
+        Float64::new(4.0).unwrap(),
+        Float64::new(8.0).unwrap(),
+    ])
+    .unwrap();
+    assert!((g.get() - 8.0f64.sqrt()).abs() < 1e-12);
+    let g = geometric_mean(&[Float64::new(3.0).unwrap()]).unwrap();
+    assert!((g.get() - 3.0).abs() < 1e-12);
+    assert_eq!(geometric_mean(&[]), None);
+    assert_eq!(
+        geometric_mean(&[Float64::new(1.0).unwrap(), Float64::new(0.0).unwrap()]),
+        None
+    );
+    assert_eq!(
+        geometric_mean(&[Float64::new(1.0).unwrap(), Float64::new(-2.0).unwrap()]),
+        None
+    );
+    let g = geometric_mean(&[Float64::MAX, Float64::MAX]).unwrap();
+    assert!((g.get() / f64::MAX - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_clamp_magnitude() {
+    assert_eq!(
+        Float32::new(5.0)
+            .unwrap()
+            .clamp_magnitude(Float32::new(3.0).unwrap()),
+        Float32::new(3.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-5.0)
+            .unwrap()
+            .clamp_magnitude(Float32::new(3.0).unwrap()),
+        Float32::new(-3.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-2.0)
+            .unwrap()
+            .clamp_magnitude(Float32::new(3.0).unwrap()),
+        Float32::new(-2.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(3.0)
+            .unwrap()
+            .clamp_magnitude(Float32::new(3.0).unwrap()),
+        Float32::new(3.0).unwrap()
+    );
+    assert_eq!(
+        Float32::new(-5.0).unwrap().clamp_magnitude(Float32::ZERO),
+        Float32::ZERO
+    );
+    assert_eq!(Float32::MIN.clamp_magnitude(Float32::MAX), Float32::MIN);
+
+    assert_eq!(
+        Float64::new(5.0)
+            .unwrap()
+            .clamp_magnitude(Float64::new(3.0).unwrap()),
+        Float64::new(3.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-5.0)
+            .unwrap()
+            .clamp_magnitude(Float64::new(3.0).unwrap()),
+        Float64::new(-3.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-2.0)
+            .unwrap()
+            .clamp_magnitude(Float64::new(3.0).unwrap()),
+        Float64::new(-2.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(3.0)
+            .unwrap()
+            .clamp_magnitude(Float64::new(3.0).unwrap()),
+        Float64::new(3.0).unwrap()
+    );
+    assert_eq!(
+        Float64::new(-5.0).unwrap().clamp_magnitude(Float64::ZERO),
+        Float64::ZERO
+    );
+    assert_eq!(Float64::MIN.clamp_magnitude(Float64::MAX), Float64::MIN);
+}
+
+#[test]
+#[should_panic(expected = "negative max_abs")]
+fn test_clamp_magnitude_negative() {
+    Float64::ONE.clamp_magnitude(-Float64::ONE);
+}
+
+#[test]
+fn test_debug_alternate() {
+    assert_eq!(
+        format!("{:#?}", Float32::MAX),
+        "Float32::MAX (saturation sentinel)"
+    );
+    assert_eq!(
+        format!("{:#?}", Float32::MIN),
+        "Float32::MIN (saturation sentinel)"
+    );
+    assert_eq!(format!("{:#?}", Float32::new(2.5).unwrap()), "2.5");
+    assert_eq!(format!("{:?}", Float32::MAX), format!("{:?}", f32::MAX));
+
+    assert_eq!(
+        format!("{:#?}", Float64::MAX),
+        "Float64::MAX (saturation sentinel)"
+    );
+    assert_eq!(
+        format!("{:#?}", Float64::MIN),
+        "Float64::MIN (saturation sentinel)"
+    );
+    assert_eq!(format!("{:#?}", Float64::new(2.5).unwrap()), "2.5");
+    assert_eq!(format!("{:?}", Float64::MIN), format!("{:?}", f64::MIN));
+}
+
+#[test]
+fn test_from_str_radix() {
+    assert_eq!(
+        Float64::from_str_radix("1010.1", 2),
+        Ok(Float64::new(10.5).unwrap())
+    );
+    assert_eq!(
+        Float64::from_str_radix("-1010.1", 2),
+        Ok(Float64::new(-10.5).unwrap())
+    );
+    assert_eq!(
+        Float64::from_str_radix("+1p3", 2),
+        Ok(Float64::new(8.0).unwrap())
+    );
+    assert_eq!(
+        Float64::from_str_radix("ff.8", 16),
+        Ok(Float64::new(255.5).unwrap())
+    );
+    assert_eq!(
+        Float64::from_str_radix("FFp-2", 16),
+        Ok(Float64::new(255.0 / 256.0).unwrap())
+    );
+    assert_eq!(
+        Float64::from_str_radix("12.5", 10),
+        Ok(Float64::new(12.5).unwrap())
+    );
+    assert_eq!(
+        Float64::from_str_radix("0.1", 10),
+        Ok(Float64::new(0.1).unwrap())
+    );
+    assert_eq!(
+        Float64::from_str_radix("1.2", 3),
+        Ok(Float64::new(5.0 / 3.0).unwrap())
+    );
+    assert_eq!(
+        Float64::from_str_radix("7p2", 8),
+        Ok(Float64::new(448.0).unwrap())
+    );
+    assert_eq!(
+        Float64::from_str_radix(".", 2),
+        Err(ParseFiniteFloatError::InvalidDigits)
+    );
+    assert_eq!(Float64::from_str_radix("0", 2), Ok(Float64::ZERO));
+    assert_eq!(
+        Float64::from_str_radix("-0.000p-99999", 2),
+        Ok(Float64::ZERO)
+    );
+
+    // Invalid.
+    assert_eq!(
+        Float64::from_str_radix("102", 2),
+        Err(ParseFiniteFloatError::InvalidDigits)
+    );
+    assert_eq!(
+        Float64::from_str_radix("1p", 2),
+        Err(ParseFiniteFloatError::InvalidDigits)
+    );
+    assert_eq!(
+        Float64::from_str_radix("", 2),
+        Err(ParseFiniteFloatError::InvalidDigits)
+    );
+    assert_eq!(
+        Float64::from_str_radix("10", 1),
+        Err(ParseFiniteFloatError::InvalidRadix(1))
+    );
+    assert_eq!(
+        Float64::from_str_radix("10", 17),
+        Err(ParseFiniteFloatError::InvalidRadix(17))
+    );
+    assert_eq!(
+        Float64::from_str_radix("nan", 16),
+        Err(ParseFiniteFloatError::Nan)
+    );
+    assert_eq!(
+        Float64::from_str_radix("-NaN", 2),
+        Err(ParseFiniteFloatError::Nan)
+    );
+
+    // Saturation.
+    assert_eq!(Float64::from_str_radix("1p1024", 2), Ok(Float64::MAX));
+    assert_eq!(Float64::from_str_radix("-1p99999", 16), Ok(Float64::MIN));
+    assert_eq!(Float64::from_str_radix("inf", 10), Ok(Float64::MAX));
+    assert_eq!(
+        Float64::from_str_radix("1p-1075", 2),
+        Ok(Float64::MIN_POSITIVE)
+    );
+    assert_eq!(
+        Float64::from_str_radix("-1p-2147483648", 3),
+        Ok(Float64::MAX_NEGATIVE)
+    );
+    assert_eq!(
+        Float64::from_str_radix("1p-1022", 2),
+        Ok(Float64::MIN_POSITIVE)
+    );
+    assert_eq!(
+        Float64::from_str_radix("1p-1023", 2),
+        Ok(Float64::MIN_POSITIVE)
+    );
+    assert_eq!(
+        Float64::from_str_radix("1p-1021", 2),
+        Ok(Float64::MIN_POSITIVE * Float64::new(2.0).unwrap())
+    );
+
+    // Large exponents scaled exactly for power-of-two radices.
+    assert_eq!(
+        Float64::from_str_radix("1p-1000", 2),
+        Ok(Float64::new(2.0f64.powi(-1000)).unwrap())
+    );
+    assert_eq!(
+        Float64::from_str_radix("1p250", 16),
+        Ok(Float64::new(2.0f64.powi(1000)).unwrap())
+    );
+    // Long mantissas.
+    assert_eq!(
+        Float64::from_str_radix("0.0000000000000000000000000000000000001", 10),
+        Ok(Float64::new(1e-37).unwrap())
+    );
+    let long = "1".repeat(100);
+    assert_eq!(
+        Float64::from_str_radix(&long, 2),
+        Ok(Float64::new(2.0f64.powi(100)).unwrap())
+    );
+    assert_eq!(
+        ParseFiniteFloatError::InvalidRadix(17).to_string(),
+        "radix 17 is not in the range 2..=16"
+    );
+    assert!(ParseFiniteFloatError::InvalidDigits.source().is_none());
+}